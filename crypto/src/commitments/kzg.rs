@@ -0,0 +1,284 @@
+use super::traits::IsCommitmentScheme;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::traits::IsPairing,
+    field::{element::FieldElement, traits::IsPrimeField},
+    msm::msm,
+    polynomial::Polynomial,
+    unsigned_integer::element::UnsignedInteger,
+};
+use std::marker::PhantomData;
+
+/// The structured reference string of KZG for a secret `s`.
+/// It holds `[g1, s * g1, ..., s^n * g1]` and `[g2, s * g2]`, where
+/// `g1` and `g2` generate the groups of the pairing.
+#[derive(Debug, Clone)]
+pub struct StructuredReferenceString<G1Point, G2Point> {
+    pub powers_main_group: Vec<G1Point>,
+    pub powers_secondary_group: [G2Point; 2],
+}
+
+impl<G1Point, G2Point> StructuredReferenceString<G1Point, G2Point>
+where
+    G1Point: IsGroup,
+    G2Point: IsGroup,
+{
+    pub fn new(powers_main_group: &[G1Point], powers_secondary_group: &[G2Point; 2]) -> Self {
+        Self {
+            powers_main_group: powers_main_group.into(),
+            powers_secondary_group: powers_secondary_group.clone(),
+        }
+    }
+}
+
+/// The KZG polynomial commitment scheme over the scalar field `F`
+/// of the groups of the pairing `P`.
+pub struct KateZaveruchaGoldberg<F: IsPrimeField, P: IsPairing> {
+    srs: StructuredReferenceString<P::G1Point, P::G2Point>,
+    phantom: PhantomData<F>,
+}
+
+impl<F: IsPrimeField, P: IsPairing> KateZaveruchaGoldberg<F, P> {
+    pub fn new(srs: StructuredReferenceString<P::G1Point, P::G2Point>) -> Self {
+        Self {
+            srs,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Returns `scalar * point`.
+fn scalar_mul<const NUM_LIMBS: usize, F, G>(scalar: &FieldElement<F>, point: &G) -> G
+where
+    F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+    G: IsGroup,
+{
+    msm(&[scalar.representative()], std::slice::from_ref(point))
+}
+
+impl<const NUM_LIMBS: usize, F, P> IsCommitmentScheme<F> for KateZaveruchaGoldberg<F, P>
+where
+    F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+    P: IsPairing,
+{
+    type Commitment = P::G1Point;
+
+    /// Returns `p(s) * g1`. Panics if the degree of `p` is larger than the
+    /// degree supported by the structured reference string.
+    fn commit(&self, p: &Polynomial<FieldElement<F>>) -> Self::Commitment {
+        let coefficients: Vec<_> = p
+            .coefficients()
+            .iter()
+            .map(|coefficient| coefficient.representative())
+            .collect();
+        assert!(
+            coefficients.len() <= self.srs.powers_main_group.len(),
+            "The degree of the polynomial exceeds the size of the structured reference string"
+        );
+        msm(
+            &coefficients,
+            &self.srs.powers_main_group[..coefficients.len()],
+        )
+    }
+
+    /// Returns the commitment to the quotient `(p(X) - y) / (X - x)`.
+    fn open(
+        &self,
+        x: &FieldElement<F>,
+        y: &FieldElement<F>,
+        p: &Polynomial<FieldElement<F>>,
+    ) -> Self::Commitment {
+        let numerator = p.clone() - Polynomial::new(std::slice::from_ref(y));
+        let denominator = Polynomial::new(&[-x, FieldElement::one()]);
+        self.commit(&numerator.div_with_ref(&denominator))
+    }
+
+    fn open_batch(
+        &self,
+        x: &FieldElement<F>,
+        ys: &[FieldElement<F>],
+        ps: &[Polynomial<FieldElement<F>>],
+        upsilon: &FieldElement<F>,
+    ) -> Self::Commitment {
+        let mut power = FieldElement::one();
+        let mut p = Polynomial::zero();
+        let mut y = FieldElement::zero();
+        for (p_i, y_i) in ps.iter().zip(ys) {
+            p = p + Polynomial::new(&[power.clone()]).mul_with_ref(p_i);
+            y += &power * y_i;
+            power = power * upsilon;
+        }
+        self.open(x, &y, &p)
+    }
+
+    /// Checks the equation `e(C - y * g1, g2) = e(proof, s * g2 - x * g2)`.
+    fn verify(
+        &self,
+        x: &FieldElement<F>,
+        y: &FieldElement<F>,
+        p_commitment: &Self::Commitment,
+        proof: &Self::Commitment,
+    ) -> bool {
+        let g1 = &self.srs.powers_main_group[0];
+        let [g2, s_g2] = &self.srs.powers_secondary_group;
+        let lhs = p_commitment.operate_with(&scalar_mul(&-y, g1));
+        let rhs = s_g2.operate_with(&scalar_mul(&-x, g2));
+        let negated_proof = scalar_mul(&-FieldElement::<F>::one(), proof);
+        let e = P::compute_batch(&[(&lhs, g2), (&negated_proof, &rhs)]);
+        e == Ok(FieldElement::one())
+    }
+
+    fn verify_batch(
+        &self,
+        x: &FieldElement<F>,
+        ys: &[FieldElement<F>],
+        p_commitments: &[Self::Commitment],
+        proof: &Self::Commitment,
+        upsilon: &FieldElement<F>,
+    ) -> bool {
+        let mut power = FieldElement::<F>::one();
+        let mut powers = Vec::with_capacity(ys.len());
+        let mut y = FieldElement::zero();
+        for y_i in ys {
+            powers.push(power.representative());
+            y += &power * y_i;
+            power = power * upsilon;
+        }
+        let p_commitment = msm(&powers, p_commitments);
+        self.verify(x, &y, &p_commitment, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lambdaworks_math::elliptic_curve::{
+        short_weierstrass::{
+            curves::bls12_381::{
+                curve::BLS12381Curve,
+                default_types::{FrElement, FrField},
+                pairing::BLS12381AtePairing,
+                twist::BLS12381TwistCurve,
+            },
+            point::ShortWeierstrassProjectivePoint,
+        },
+        traits::IsEllipticCurve,
+    };
+
+    type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+    type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+    type Kzg = KateZaveruchaGoldberg<FrField, BLS12381AtePairing>;
+
+    /// Creates a structured reference string for polynomials of degree
+    /// up to 9 from the toy secret `s = 0x1b0f`.
+    fn create_srs() -> StructuredReferenceString<G1Point, G2Point> {
+        let s = 0x1b0f_u128;
+        let g1 = BLS12381Curve::generator();
+        let g2 = BLS12381TwistCurve::generator();
+        let powers_main_group: Vec<G1Point> = (0..10)
+            .scan(g1, |power, _| {
+                let current = power.clone();
+                *power = power.operate_with_self(s);
+                Some(current)
+            })
+            .collect();
+        let powers_secondary_group = [g2.clone(), g2.operate_with_self(s)];
+        StructuredReferenceString::new(&powers_main_group, &powers_secondary_group)
+    }
+
+    fn polynomial() -> Polynomial<FrElement> {
+        Polynomial::new(&[
+            FrElement::from(5),
+            FrElement::from(3),
+            -FrElement::from(7),
+            FrElement::from(1),
+        ])
+    }
+
+    #[test]
+    fn commitment_is_the_evaluation_at_the_secret_times_the_generator() {
+        let kzg = Kzg::new(create_srs());
+        let p = polynomial();
+        let p_at_s = p.evaluate(&FrElement::from(0x1b0f));
+        assert_eq!(
+            kzg.commit(&p),
+            msm(&[p_at_s.representative()], &[BLS12381Curve::generator()])
+        );
+    }
+
+    #[test]
+    fn kzg_1() {
+        let kzg = Kzg::new(create_srs());
+        let p = Polynomial::<FrElement>::new(&[FieldElement::one(), FieldElement::one()]);
+        let p_commitment = kzg.commit(&p);
+        let x = -FieldElement::one();
+        let y = p.evaluate(&x);
+        let proof = kzg.open(&x, &y, &p);
+        assert_eq!(y, FieldElement::zero());
+        assert_eq!(proof, BLS12381Curve::generator());
+        assert!(kzg.verify(&x, &y, &p_commitment, &proof));
+    }
+
+    #[test]
+    fn opening_is_verified() {
+        let kzg = Kzg::new(create_srs());
+        let p = polynomial();
+        let p_commitment = kzg.commit(&p);
+        let x = FrElement::from(42);
+        let y = p.evaluate(&x);
+        let proof = kzg.open(&x, &y, &p);
+        assert!(kzg.verify(&x, &y, &p_commitment, &proof));
+    }
+
+    #[test]
+    fn opening_with_a_wrong_evaluation_is_rejected() {
+        let kzg = Kzg::new(create_srs());
+        let p = polynomial();
+        let p_commitment = kzg.commit(&p);
+        let x = FrElement::from(42);
+        let y = p.evaluate(&x) + FrElement::one();
+        let proof = kzg.open(&x, &y, &p);
+        assert!(!kzg.verify(&x, &y, &p_commitment, &proof));
+        let proof = kzg.open(&x, &p.evaluate(&x), &p);
+        assert!(!kzg.verify(&x, &y, &p_commitment, &proof));
+    }
+
+    #[test]
+    fn opening_of_a_constant_polynomial_is_the_point_at_infinity() {
+        let kzg = Kzg::new(create_srs());
+        let p = Polynomial::new(&[FrElement::from(9)]);
+        let p_commitment = kzg.commit(&p);
+        let x = FrElement::from(3);
+        let y = FrElement::from(9);
+        let proof = kzg.open(&x, &y, &p);
+        assert!(proof.is_neutral_element());
+        assert!(kzg.verify(&x, &y, &p_commitment, &proof));
+    }
+
+    #[test]
+    fn batch_opening_is_verified() {
+        let kzg = Kzg::new(create_srs());
+        let ps = [
+            polynomial(),
+            Polynomial::new(&[FrElement::from(2), FrElement::from(11)]),
+            Polynomial::new(&[FrElement::from(8)]),
+        ];
+        let p_commitments: Vec<_> = ps.iter().map(|p| kzg.commit(p)).collect();
+        let x = FrElement::from(17);
+        let ys: Vec<_> = ps.iter().map(|p| p.evaluate(&x)).collect();
+        let upsilon = FrElement::from(1234567);
+        let proof = kzg.open_batch(&x, &ys, &ps, &upsilon);
+        assert!(kzg.verify_batch(&x, &ys, &p_commitments, &proof, &upsilon));
+
+        let mut wrong_ys = ys.clone();
+        wrong_ys[1] = wrong_ys[1].clone() + FrElement::one();
+        assert!(!kzg.verify_batch(&x, &wrong_ys, &p_commitments, &proof, &upsilon));
+    }
+
+    #[test]
+    #[should_panic]
+    fn committing_to_a_polynomial_larger_than_the_srs_panics() {
+        let kzg = Kzg::new(create_srs());
+        kzg.commit(&Polynomial::new_monomial(FrElement::one(), 10));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod kzg;
+pub mod traits;
@@ -0,0 +1,50 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    polynomial::Polynomial,
+};
+
+/// Interface to polynomial commitment schemes.
+pub trait IsCommitmentScheme<F: IsField> {
+    type Commitment;
+
+    /// Returns the commitment to the polynomial `p`.
+    fn commit(&self, p: &Polynomial<FieldElement<F>>) -> Self::Commitment;
+
+    /// Returns a proof that `p(x) = y`.
+    fn open(
+        &self,
+        x: &FieldElement<F>,
+        y: &FieldElement<F>,
+        p: &Polynomial<FieldElement<F>>,
+    ) -> Self::Commitment;
+
+    /// Returns a single proof that `ps[i](x) = ys[i]` for every `i`.
+    /// The polynomials are combined with powers of the random element `upsilon`.
+    fn open_batch(
+        &self,
+        x: &FieldElement<F>,
+        ys: &[FieldElement<F>],
+        ps: &[Polynomial<FieldElement<F>>],
+        upsilon: &FieldElement<F>,
+    ) -> Self::Commitment;
+
+    /// Checks that `proof` shows that the polynomial committed in
+    /// `p_commitment` evaluates to `y` at `x`.
+    fn verify(
+        &self,
+        x: &FieldElement<F>,
+        y: &FieldElement<F>,
+        p_commitment: &Self::Commitment,
+        proof: &Self::Commitment,
+    ) -> bool;
+
+    /// Checks a proof generated by `open_batch` with the same `upsilon`.
+    fn verify_batch(
+        &self,
+        x: &FieldElement<F>,
+        ys: &[FieldElement<F>],
+        p_commitments: &[Self::Commitment],
+        proof: &Self::Commitment,
+        upsilon: &FieldElement<F>,
+    ) -> bool;
+}
@@ -14,7 +14,7 @@ impl Transcript {
 
     #[allow(unused)]
     fn append(&mut self, new_data: &[u8]) {
-        self.hasher.update(&mut new_data.to_owned());
+        self.hasher.update(new_data);
     }
 
     #[allow(unused)]
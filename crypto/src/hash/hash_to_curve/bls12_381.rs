@@ -0,0 +1,427 @@
+//! The `BLS12381G1_XMD:SHA-256_SSWU_RO_` and `BLS12381G2_XMD:SHA-256_SSWU_RO_` suites
+//! of RFC 9380, section 8.8.
+use super::{expand_message::expand_message_xmd, HashToCurveError};
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::bls12_381::{
+                curve::BLS12381Curve,
+                field_extension::{BLS12381PrimeField, LevelOneField, BLS12381_PRIME_FIELD_ORDER},
+                twist::BLS12381TwistCurve,
+            },
+            point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, traits::IsField},
+    traits::ByteConversion,
+    unsigned_integer::element::U384,
+};
+use sha2::Sha256;
+
+pub type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+pub type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+type FpElement = FieldElement<BLS12381PrimeField>;
+type Fp2Element = FieldElement<LevelOneField>;
+
+/// Number of uniform bytes reduced into each base field element, `L` in the RFC.
+const L: usize = 64;
+
+/// Coefficient `A'` of the curve 11-isogenous to G1 where the SSWU map is evaluated.
+const G1_ISO_A: &str = "144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d";
+/// Coefficient `B'` of the curve 11-isogenous to G1.
+const G1_ISO_B: &str = "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0";
+/// The non-square `Z` of the G1 SSWU map.
+const G1_Z: u64 = 11;
+
+// Coefficients of the 11-isogeny map of RFC 9380, appendix E.2, in ascending degree.
+const G1_X_NUM: [&str; 12] = [
+    "11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7",
+    "17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb",
+    "d54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0",
+    "1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861",
+    "e99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9",
+    "1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983",
+    "d6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84",
+    "17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e",
+    "80d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317",
+    "169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e",
+    "10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b",
+    "6e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229",
+];
+const G1_X_DEN: [&str; 11] = [
+    "8ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c",
+    "12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff",
+    "b2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19",
+    "3425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8",
+    "13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e",
+    "e7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5",
+    "772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a",
+    "14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e",
+    "a10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641",
+    "95fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a",
+    "1",
+];
+const G1_Y_NUM: [&str; 16] = [
+    "90d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33",
+    "134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696",
+    "cc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6",
+    "1f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb",
+    "8cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb",
+    "16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0",
+    "4ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2",
+    "987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29",
+    "9fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587",
+    "e1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30",
+    "19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132",
+    "18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e",
+    "b182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8",
+    "245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133",
+    "5c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b",
+    "15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604",
+];
+const G1_Y_DEN: [&str; 16] = [
+    "16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1",
+    "1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d",
+    "58df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2",
+    "16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416",
+    "be0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d",
+    "8d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac",
+    "166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c",
+    "16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9",
+    "1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a",
+    "167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55",
+    "4d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8",
+    "accbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092",
+    "ad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc",
+    "2660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7",
+    "e0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f",
+    "1",
+];
+
+// Coefficients of the 3-isogeny map of RFC 9380, appendix E.3, in ascending degree.
+// Each one is written as `[c0, c1]` for `c0 + c1 * u`.
+const G2_X_NUM: [[&str; 2]; 4] = [
+    [
+        "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6",
+        "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6",
+    ],
+    [
+        "0",
+        "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71a",
+    ],
+    [
+        "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71e",
+        "8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38d",
+    ],
+    [
+        "171d6541fa38ccfaed6dea691f5fb614cb14b4e7f4e810aa22d6108f142b85757098e38d0f671c7188e2aaaaaaaa5ed1",
+        "0",
+    ],
+];
+const G2_X_DEN: [[&str; 2]; 3] = [
+    [
+        "0",
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa63",
+    ],
+    [
+        "c",
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa9f",
+    ],
+    ["1", "0"],
+];
+const G2_Y_NUM: [[&str; 2]; 4] = [
+    [
+        "1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706",
+        "1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706",
+    ],
+    [
+        "0",
+        "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97be",
+    ],
+    [
+        "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71c",
+        "8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38f",
+    ],
+    [
+        "124c9ad43b6cf79bfbf7043de3811ad0761b0f37a1e26286b0e977c69aa274524e79097a56dc4bd9e1b371c71c718b10",
+        "0",
+    ],
+];
+const G2_Y_DEN: [[&str; 2]; 4] = [
+    [
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb",
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb",
+    ],
+    [
+        "0",
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa9d3",
+    ],
+    [
+        "12",
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa99",
+    ],
+    ["1", "0"],
+];
+
+/// Hashes `msg` to a point of G1 with the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite,
+/// using `dst` as domain separation tag.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> Result<G1Point, HashToCurveError> {
+    let u = hash_to_field_fp(msg, dst, 2)?;
+    let q = map_to_curve_g1(&u[0]).operate_with(&map_to_curve_g1(&u[1]));
+    Ok(BLS12381Curve::clear_cofactor(&q))
+}
+
+/// Hashes `msg` to a point of G2 with the `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite,
+/// using `dst` as domain separation tag.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> Result<G2Point, HashToCurveError> {
+    let u = hash_to_field_fp2(msg, dst, 2)?;
+    let q = map_to_curve_g2(&u[0]).operate_with(&map_to_curve_g2(&u[1]));
+    Ok(BLS12381TwistCurve::clear_cofactor(&q))
+}
+
+/// Hashes `msg` to `count` elements of the base field of BLS12-381.
+pub fn hash_to_field_fp(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<FpElement>, HashToCurveError> {
+    let uniform_bytes = expand_message_xmd::<Sha256>(msg, dst, count * L)?;
+    Ok(uniform_bytes.chunks_exact(L).map(fp_from_bytes).collect())
+}
+
+/// Hashes `msg` to `count` elements of the quadratic extension of the base field of BLS12-381.
+pub fn hash_to_field_fp2(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<Fp2Element>, HashToCurveError> {
+    let uniform_bytes = expand_message_xmd::<Sha256>(msg, dst, count * 2 * L)?;
+    Ok(uniform_bytes
+        .chunks_exact(2 * L)
+        .map(|chunk| Fp2Element::new([fp_from_bytes(&chunk[..L]), fp_from_bytes(&chunk[L..])]))
+        .collect())
+}
+
+/// Maps a base field element to a point of the curve E1, which still has to be multiplied
+/// by the cofactor to land in G1.
+pub fn map_to_curve_g1(u: &FpElement) -> G1Point {
+    let a = FpElement::new_base(G1_ISO_A);
+    let b = FpElement::new_base(G1_ISO_B);
+    let z = FpElement::from(G1_Z);
+    let (x, y) = simplified_swu(u, &a, &b, &z);
+
+    let to_fp = |coefficients: &[&str]| -> Vec<FpElement> {
+        coefficients
+            .iter()
+            .map(|c| FpElement::new_base(c))
+            .collect()
+    };
+    iso_map(
+        &x,
+        &y,
+        &to_fp(&G1_X_NUM),
+        &to_fp(&G1_X_DEN),
+        &to_fp(&G1_Y_NUM),
+        &to_fp(&G1_Y_DEN),
+    )
+}
+
+/// Maps an element of the quadratic extension to a point of the curve E2, which still has
+/// to be multiplied by the cofactor to land in G2.
+pub fn map_to_curve_g2(u: &Fp2Element) -> G2Point {
+    let a = Fp2Element::new([FpElement::zero(), FpElement::from(240)]);
+    let b = Fp2Element::new([FpElement::from(1012), FpElement::from(1012)]);
+    let z = -Fp2Element::new([FpElement::from(2), FpElement::one()]);
+    let (x, y) = simplified_swu(u, &a, &b, &z);
+
+    let to_fp2 = |coefficients: &[[&str; 2]]| -> Vec<Fp2Element> {
+        coefficients
+            .iter()
+            .map(|[c0, c1]| Fp2Element::new([FpElement::new_base(c0), FpElement::new_base(c1)]))
+            .collect()
+    };
+    iso_map(
+        &x,
+        &y,
+        &to_fp2(&G2_X_NUM),
+        &to_fp2(&G2_X_DEN),
+        &to_fp2(&G2_Y_NUM),
+        &to_fp2(&G2_Y_DEN),
+    )
+}
+
+/// Reduces `L` big-endian bytes modulo the BLS12-381 base field order.
+fn fp_from_bytes(bytes: &[u8]) -> FpElement {
+    let mut wide = [0; 96];
+    wide[96 - L..].copy_from_slice(bytes);
+    let hi = U384::from_bytes_be(&wide[..48]).expect("the length is 48 bytes");
+    let lo = U384::from_bytes_be(&wide[48..]).expect("the length is 48 bytes");
+    FpElement::new(U384::reduce_wide(&hi, &lo, &BLS12381_PRIME_FIELD_ORDER))
+}
+
+/// Fields over which the simplified SWU map is evaluated.
+trait IsSwuField: IsField + Sized {
+    /// The `sgn0` function of RFC 9380, section 4.1.
+    fn sgn0(x: &FieldElement<Self>) -> bool;
+
+    /// Returns a square root of `x`, or `None` if it is not a square.
+    fn sqrt(x: &FieldElement<Self>) -> Option<FieldElement<Self>>;
+}
+
+impl IsSwuField for BLS12381PrimeField {
+    fn sgn0(x: &FpElement) -> bool {
+        x.representative().limbs[5] & 1 == 1
+    }
+
+    fn sqrt(x: &FpElement) -> Option<FpElement> {
+        x.sqrt().map(|(root, _)| root)
+    }
+}
+
+impl IsSwuField for LevelOneField {
+    fn sgn0(x: &Fp2Element) -> bool {
+        let [x0, x1] = x.value();
+        BLS12381PrimeField::sgn0(x0) || (*x0 == FpElement::zero() && BLS12381PrimeField::sgn0(x1))
+    }
+
+    fn sqrt(x: &Fp2Element) -> Option<Fp2Element> {
+        x.sqrt().map(|(root, _)| root)
+    }
+}
+
+/// The simplified Shallue-van de Woestijne-Ulas map of RFC 9380, section 6.6.2, to the
+/// curve `y^2 = x^3 + a * x + b`. `z` must be a non-square such that `g(b / (z * a))`
+/// is a square.
+fn simplified_swu<F: IsSwuField>(
+    u: &FieldElement<F>,
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+    z: &FieldElement<F>,
+) -> (FieldElement<F>, FieldElement<F>) {
+    let g = |x: &FieldElement<F>| x * x * x + a * x + b;
+
+    let z_u2 = z * u * u;
+    let x1 = match (&z_u2 * &z_u2 + &z_u2).try_inv() {
+        Ok(tv1) => (-b / a) * (FieldElement::one() + tv1),
+        // `inv0` of the RFC maps zero to zero, which corresponds to this exceptional case.
+        Err(_) => b / (z * a),
+    };
+    let (x, y) = match F::sqrt(&g(&x1)) {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z_u2 * x1;
+            let y2 = F::sqrt(&g(&x2)).expect("g(x2) is a square when g(x1) is not");
+            (x2, y2)
+        }
+    };
+    if F::sgn0(u) != F::sgn0(&y) {
+        (x, -y)
+    } else {
+        (x, y)
+    }
+}
+
+/// Evaluates the isogeny `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`,
+/// with the polynomials given by their coefficients in ascending degree. The points
+/// where the denominators vanish are mapped to the neutral element.
+fn iso_map<E: IsShortWeierstrass>(
+    x: &FieldElement<E::BaseField>,
+    y: &FieldElement<E::BaseField>,
+    x_num: &[FieldElement<E::BaseField>],
+    x_den: &[FieldElement<E::BaseField>],
+    y_num: &[FieldElement<E::BaseField>],
+    y_den: &[FieldElement<E::BaseField>],
+) -> ShortWeierstrassProjectivePoint<E> {
+    let evaluate = |coefficients: &[FieldElement<E::BaseField>]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(FieldElement::zero(), |acc, coefficient| {
+                acc * x + coefficient
+            })
+    };
+    match (evaluate(x_den).try_inv(), evaluate(y_den).try_inv()) {
+        (Ok(x_den_inv), Ok(y_den_inv)) => ShortWeierstrassProjectivePoint::new([
+            evaluate(x_num) * x_den_inv,
+            y * evaluate(y_num) * y_den_inv,
+            FieldElement::one(),
+        ]),
+        _ => ShortWeierstrassProjectivePoint::neutral_element(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    const G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn hash_to_g1_matches_the_rfc_test_vectors() {
+        // RFC 9380, appendix J.9.1. Points are encoded as `x || y`.
+        let cases: [(&[u8], &str); 3] = [
+            (
+                b"",
+                "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1\
+                 08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            ),
+            (
+                b"abc",
+                "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903\
+                 0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            ),
+            (
+                b"abcdef0123456789",
+                "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98\
+                 03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            ),
+        ];
+        for (msg, expected) in cases {
+            let point = hash_to_g1(msg, G1_DST).unwrap();
+            assert!(BLS12381Curve::is_in_subgroup(&point));
+            assert_eq!(to_hex(&point.to_affine().to_bytes_be()), expected);
+        }
+    }
+
+    #[test]
+    fn hash_to_g2_matches_the_rfc_test_vectors() {
+        // RFC 9380, appendix J.10.1. Points are encoded as `x.c1 || x.c0 || y.c1 || y.c0`.
+        let cases: [(&[u8], &str); 2] = [
+            (
+                b"",
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d\
+                 0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a\
+                 12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6\
+                 0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            ),
+            (
+                b"abc",
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8\
+                 02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6\
+                 00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16\
+                 1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            ),
+        ];
+        for (msg, expected) in cases {
+            let point = hash_to_g2(msg, G2_DST).unwrap();
+            assert!(BLS12381TwistCurve::is_in_subgroup(&point));
+            assert_eq!(to_hex(&point.to_affine().to_bytes_be()), expected);
+        }
+    }
+
+    #[test]
+    fn hash_to_g1_depends_on_the_domain_separation_tag() {
+        assert_ne!(
+            hash_to_g1(b"abc", G1_DST).unwrap(),
+            hash_to_g1(b"abc", b"ANOTHER-DST").unwrap()
+        );
+    }
+}
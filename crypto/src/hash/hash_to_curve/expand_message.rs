@@ -0,0 +1,125 @@
+use super::HashToCurveError;
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+
+/// Prefix of the hash of domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes with the hash function `H`,
+/// domain separated by `dst`. Implements `expand_message_xmd` of RFC 9380, section 5.3.1,
+/// including the hashing of long tags of section 5.3.3.
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(HashToCurveError::OutputTooLong);
+    }
+
+    let dst = if dst.len() > 255 {
+        H::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    };
+    let dst_prime = [dst.as_slice(), &[dst.len() as u8]].concat();
+
+    let b_0 = H::new()
+        .chain_update(vec![0; H::block_size()])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = b_i.to_vec();
+    for i in 2..=ell {
+        let mixed: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = H::new()
+            .chain_update(mixed)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_matches_the_rfc_test_vectors() {
+        // RFC 9380, appendix K.1.
+        let cases: [(&[u8], usize, &str); 4] = [
+            (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+        for (msg, len_in_bytes, expected) in cases {
+            assert_eq!(
+                expand_message_xmd::<Sha256>(msg, DST, len_in_bytes).unwrap(),
+                hex_to_bytes(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_hashes_long_domain_separation_tags() {
+        // RFC 9380, appendix K.2.
+        let dst = [
+            b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-".as_slice(),
+            &[b'1'; 208],
+        ]
+        .concat();
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"", &dst, 0x20).unwrap(),
+            hex_to_bytes("e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3")
+        );
+    }
+
+    #[test]
+    fn expand_message_xmd_rejects_too_long_outputs() {
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", DST, 255 * 32 + 1),
+            Err(HashToCurveError::OutputTooLong)
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", DST, 255 * 32)
+                .unwrap()
+                .len(),
+            255 * 32
+        );
+    }
+}
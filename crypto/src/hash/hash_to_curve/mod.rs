@@ -0,0 +1,11 @@
+//! Hashing to elliptic curves as specified in RFC 9380
+//! (https://www.rfc-editor.org/rfc/rfc9380).
+
+pub mod bls12_381;
+pub mod expand_message;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The requested number of bytes is larger than what `expand_message` can output.
+    OutputTooLong,
+}
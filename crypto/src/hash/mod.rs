@@ -1,2 +1,3 @@
+pub mod hash_to_curve;
 pub mod poseidon;
 pub mod traits;
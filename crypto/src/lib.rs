@@ -1,3 +1,5 @@
+pub mod commitments;
 pub mod fiat_shamir;
 pub mod hash;
 pub mod merkle_tree;
+pub mod signatures;
@@ -88,7 +88,10 @@ impl<F: IsField, H: IsCryptoHash<F> + Clone> MerkleTree<F, H> {
         let mut pos = pos;
 
         while pos != ROOT {
-            merkle_path.push((self.nodes[sibling_index(pos)].clone(), pos % 2 == 0));
+            merkle_path.push((
+                self.nodes[sibling_index(pos)].clone(),
+                pos.is_multiple_of(2),
+            ));
             pos = parent_index(pos);
         }
 
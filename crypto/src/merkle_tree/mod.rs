@@ -37,7 +37,6 @@ impl<F: IsField> IsCryptoHash<F> for DefaultHasher {
 }
 
 #[cfg(test)]
-
 mod tests {
 
     use crate::merkle_tree::{proof::Proof, U64MerkleTree, U64Proof, U64FE};
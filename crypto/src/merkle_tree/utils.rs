@@ -13,7 +13,7 @@ pub fn hash_leaves<F: IsField, H: IsCryptoHash<F>>(
 }
 
 pub fn sibling_index(node_index: usize) -> usize {
-    if node_index % 2 == 0 {
+    if node_index.is_multiple_of(2) {
         node_index - 1
     } else {
         node_index + 1
@@ -21,7 +21,7 @@ pub fn sibling_index(node_index: usize) -> usize {
 }
 
 pub fn parent_index(node_index: usize) -> usize {
-    if node_index % 2 == 0 {
+    if node_index.is_multiple_of(2) {
         (node_index - 1) / 2
     } else {
         node_index / 2
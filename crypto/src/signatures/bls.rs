@@ -0,0 +1,595 @@
+//! BLS signatures over BLS12-381 with the proof of possession scheme of
+//! https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05.
+use crate::hash::hash_to_curve::bls12_381::{hash_to_g1, hash_to_g2};
+use hkdf::Hkdf;
+#[cfg(feature = "constant-time")]
+use lambdaworks_math::constant_time::IsConstantTimeGroup;
+use lambdaworks_math::{
+    cyclic_group::{IsGroup, IsGroupWithNegation},
+    elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{
+            compression::{compress_g1_point, compress_g2_point},
+            curve::BLS12381Curve,
+            default_types::{FrConfig, FrElement},
+            pairing::BLS12381AtePairing,
+            twist::BLS12381TwistCurve,
+        },
+        traits::{IsEllipticCurve, IsPairing},
+    },
+    field::{
+        element::FieldElement, fields::montgomery_backed_prime_fields::IsMontgomeryConfiguration,
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use sha2::{Digest, Sha256};
+use std::{fmt, marker::PhantomData};
+
+/// Salt of the first iteration of KeyGen.
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+/// Minimum length of the input key material of KeyGen.
+const MIN_IKM_LENGTH: usize = 32;
+/// Number of bytes reduced into a secret key by KeyGen, `L` in the draft.
+const KEYGEN_OUTPUT_LENGTH: usize = 48;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlsError {
+    /// The input key material is shorter than 32 bytes.
+    ShortKeyMaterial,
+    /// Secret keys must be non-zero.
+    ZeroSecretKey,
+    /// There are no signatures to aggregate.
+    EmptyAggregate,
+    /// A signature is not in the prime order subgroup.
+    InvalidSignature,
+}
+
+/// A BLS secret key, which is a non-zero element of the scalar field of BLS12-381.
+#[derive(Clone)]
+pub struct SecretKey(FrElement);
+
+/// Redacts the value, so that secret keys don't end up in logs.
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+impl SecretKey {
+    pub fn new(value: FrElement) -> Result<Self, BlsError> {
+        if value == FrElement::zero() {
+            Err(BlsError::ZeroSecretKey)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Derives a secret key from at least 32 bytes of input key material `ikm`,
+    /// following KeyGen of section 2.3 of the draft.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Self, BlsError> {
+        if ikm.len() < MIN_IKM_LENGTH {
+            return Err(BlsError::ShortKeyMaterial);
+        }
+        let ikm = [ikm, &[0]].concat();
+        let info = [key_info, &(KEYGEN_OUTPUT_LENGTH as u16).to_be_bytes()].concat();
+        let mut salt = KEYGEN_SALT.to_vec();
+        loop {
+            salt = Sha256::digest(&salt).to_vec();
+            let mut okm = [0; KEYGEN_OUTPUT_LENGTH];
+            Hkdf::<Sha256>::new(Some(&salt), &ikm)
+                .expand(&info, &mut okm)
+                .expect("the output length is valid for HKDF-SHA256");
+            if let Ok(secret_key) = Self::new(fr_from_bytes(&okm)) {
+                return Ok(secret_key);
+            }
+        }
+    }
+
+    pub fn value(&self) -> &FrElement {
+        &self.0
+    }
+}
+
+/// Reduces big-endian bytes modulo the order of the scalar field.
+fn fr_from_bytes(bytes: &[u8; KEYGEN_OUTPUT_LENGTH]) -> FrElement {
+    let mut wide = [0; 64];
+    wide[64 - KEYGEN_OUTPUT_LENGTH..].copy_from_slice(bytes);
+    let hi = U256::from_bytes_be(&wide[..32]).expect("the length is 32 bytes");
+    let lo = U256::from_bytes_be(&wide[32..]).expect("the length is 32 bytes");
+    FrElement::new(U256::reduce_wide(&hi, &lo, &FrConfig::MODULUS))
+}
+
+/// Groups whose elements can be multiplied by a secret key, to derive public keys
+/// and signatures. With the `constant-time` feature, the multiplication uses a
+/// Montgomery ladder, so its running time does not depend on the secret key.
+pub trait MultiplyBySecretKey: IsGroup {
+    fn multiply_by_secret_key(&self, secret_key: &SecretKey) -> Self;
+}
+
+#[cfg(not(feature = "constant-time"))]
+impl<P: IsGroupWithNegation> MultiplyBySecretKey for P {
+    fn multiply_by_secret_key(&self, secret_key: &SecretKey) -> Self {
+        self.operate_with_self_wnaf(secret_key.0.representative())
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<P: IsConstantTimeGroup> MultiplyBySecretKey for P {
+    fn multiply_by_secret_key(&self, secret_key: &SecretKey) -> Self {
+        self.operate_with_self_constant_time(&secret_key.0.representative())
+    }
+}
+
+/// The groups of public keys and signatures of a BLS ciphersuite, together with
+/// its domain separation tags.
+pub trait IsBlsVariant {
+    type PublicKeyCurve: IsEllipticCurve;
+    type SignatureCurve: IsEllipticCurve;
+
+    /// Domain separation tag used to hash messages.
+    const SIGNATURE_DST: &'static [u8];
+    /// Domain separation tag used to hash public keys in proofs of possession.
+    const POP_DST: &'static [u8];
+
+    /// Hashes `msg` to the subgroup of signatures.
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Signature<Self>;
+
+    /// Returns the compressed encoding of `public_key`.
+    fn public_key_to_bytes(public_key: &PublicKey<Self>) -> Vec<u8>;
+
+    /// Checks that the product of the pairings of the public key and signature
+    /// group elements of `pairs` is one.
+    fn pairing_product_is_one(pairs: &[(&PublicKey<Self>, &Signature<Self>)]) -> bool;
+}
+
+pub type PublicKey<V> =
+    <<V as IsBlsVariant>::PublicKeyCurve as IsEllipticCurve>::PointRepresentation;
+pub type Signature<V> =
+    <<V as IsBlsVariant>::SignatureCurve as IsEllipticCurve>::PointRepresentation;
+
+/// Minimal public key size: public keys in G1 and signatures in G2.
+#[derive(Debug)]
+pub struct MinPk;
+
+impl IsBlsVariant for MinPk {
+    type PublicKeyCurve = BLS12381Curve;
+    type SignatureCurve = BLS12381TwistCurve;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Signature<Self> {
+        hash_to_g2(msg, dst).expect("the suite expands messages to a valid length")
+    }
+
+    fn public_key_to_bytes(public_key: &PublicKey<Self>) -> Vec<u8> {
+        compress_g1_point(public_key).to_vec()
+    }
+
+    fn pairing_product_is_one(pairs: &[(&PublicKey<Self>, &Signature<Self>)]) -> bool {
+        BLS12381AtePairing::compute_batch(pairs) == Ok(FieldElement::one())
+    }
+}
+
+/// Minimal signature size: public keys in G2 and signatures in G1.
+#[derive(Debug)]
+pub struct MinSig;
+
+impl IsBlsVariant for MinSig {
+    type PublicKeyCurve = BLS12381TwistCurve;
+    type SignatureCurve = BLS12381Curve;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn hash_to_signature_group(msg: &[u8], dst: &[u8]) -> Signature<Self> {
+        hash_to_g1(msg, dst).expect("the suite expands messages to a valid length")
+    }
+
+    fn public_key_to_bytes(public_key: &PublicKey<Self>) -> Vec<u8> {
+        compress_g2_point(public_key).to_vec()
+    }
+
+    fn pairing_product_is_one(pairs: &[(&PublicKey<Self>, &Signature<Self>)]) -> bool {
+        let pairs: Vec<_> = pairs
+            .iter()
+            .map(|(public_key, signature)| (*signature, *public_key))
+            .collect();
+        BLS12381AtePairing::compute_batch(&pairs) == Ok(FieldElement::one())
+    }
+}
+
+/// The BLS signature scheme with proofs of possession in the variant `V`.
+pub struct BlsSignatureScheme<V: IsBlsVariant> {
+    phantom: PhantomData<V>,
+}
+
+pub type MinPkBls = BlsSignatureScheme<MinPk>;
+pub type MinSigBls = BlsSignatureScheme<MinSig>;
+
+impl<V: IsBlsVariant> BlsSignatureScheme<V> {
+    pub fn sk_to_pk(secret_key: &SecretKey) -> PublicKey<V>
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+    {
+        V::PublicKeyCurve::generator().multiply_by_secret_key(secret_key)
+    }
+
+    /// Checks that `public_key` is a non-neutral element of the prime order subgroup.
+    pub fn key_validate(public_key: &PublicKey<V>) -> bool {
+        !public_key.is_neutral_element() && V::PublicKeyCurve::is_in_subgroup(public_key)
+    }
+
+    pub fn sign(secret_key: &SecretKey, msg: &[u8]) -> Signature<V>
+    where
+        Signature<V>: MultiplyBySecretKey,
+    {
+        Self::core_sign(secret_key, msg, V::SIGNATURE_DST)
+    }
+
+    pub fn verify(public_key: &PublicKey<V>, msg: &[u8], signature: &Signature<V>) -> bool {
+        Self::core_verify(public_key, msg, signature, V::SIGNATURE_DST)
+    }
+
+    /// Adds up `signatures` into a single one.
+    pub fn aggregate(signatures: &[Signature<V>]) -> Result<Signature<V>, BlsError> {
+        if signatures.is_empty() {
+            return Err(BlsError::EmptyAggregate);
+        }
+        if !signatures.iter().all(V::SignatureCurve::is_in_subgroup) {
+            return Err(BlsError::InvalidSignature);
+        }
+        Ok(signatures
+            .iter()
+            .fold(Signature::<V>::neutral_element(), |acc, signature| {
+                acc.operate_with(signature)
+            }))
+    }
+
+    /// Verifies an aggregate of signatures of `msgs[i]` by `public_keys[i]`.
+    /// Messages may repeat, since every public key must have a verified proof of possession.
+    pub fn aggregate_verify(
+        public_keys: &[PublicKey<V>],
+        msgs: &[&[u8]],
+        signature: &Signature<V>,
+    ) -> bool {
+        if public_keys.is_empty()
+            || public_keys.len() != msgs.len()
+            || !V::SignatureCurve::is_in_subgroup(signature)
+            || !public_keys.iter().all(Self::key_validate)
+        {
+            return false;
+        }
+        let hashes: Vec<_> = msgs
+            .iter()
+            .map(|msg| V::hash_to_signature_group(msg, V::SIGNATURE_DST))
+            .collect();
+        let minus_generator = V::PublicKeyCurve::generator().neg();
+        let mut pairs: Vec<_> = public_keys.iter().zip(&hashes).collect();
+        pairs.push((&minus_generator, signature));
+        V::pairing_product_is_one(&pairs)
+    }
+
+    /// Verifies an aggregate of signatures of the same `msg` by `public_keys`.
+    /// Every public key must have a verified proof of possession.
+    pub fn fast_aggregate_verify(
+        public_keys: &[PublicKey<V>],
+        msg: &[u8],
+        signature: &Signature<V>,
+    ) -> bool {
+        if public_keys.is_empty() {
+            return false;
+        }
+        let aggregate = public_keys
+            .iter()
+            .fold(PublicKey::<V>::neutral_element(), |acc, public_key| {
+                acc.operate_with(public_key)
+            });
+        Self::verify(&aggregate, msg, signature)
+    }
+
+    /// Returns a proof that the owner of `secret_key` knows it.
+    pub fn pop_prove(secret_key: &SecretKey) -> Signature<V>
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+        Signature<V>: MultiplyBySecretKey,
+    {
+        let public_key = V::public_key_to_bytes(&Self::sk_to_pk(secret_key));
+        Self::core_sign(secret_key, &public_key, V::POP_DST)
+    }
+
+    pub fn pop_verify(public_key: &PublicKey<V>, proof: &Signature<V>) -> bool {
+        let public_key_bytes = V::public_key_to_bytes(public_key);
+        Self::core_verify(public_key, &public_key_bytes, proof, V::POP_DST)
+    }
+
+    fn core_sign(secret_key: &SecretKey, msg: &[u8], dst: &[u8]) -> Signature<V>
+    where
+        Signature<V>: MultiplyBySecretKey,
+    {
+        V::hash_to_signature_group(msg, dst).multiply_by_secret_key(secret_key)
+    }
+
+    /// Checks the equation `e(public_key, H(msg)) = e(g, signature)`.
+    fn core_verify(
+        public_key: &PublicKey<V>,
+        msg: &[u8],
+        signature: &Signature<V>,
+        dst: &[u8],
+    ) -> bool {
+        if !V::SignatureCurve::is_in_subgroup(signature) || !Self::key_validate(public_key) {
+            return false;
+        }
+        let hash = V::hash_to_signature_group(msg, dst);
+        let minus_generator = V::PublicKeyCurve::generator().neg();
+        V::pairing_product_is_one(&[(public_key, &hash), (&minus_generator, signature)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lambdaworks_math::unsigned_integer::element::U256;
+
+    fn test_secret_key(seed: u8) -> SecretKey {
+        SecretKey::key_gen(&[seed; 32], b"").unwrap()
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Secret key and message of the Ethereum consensus `sign` test vectors.
+    fn reference_secret_key() -> SecretKey {
+        SecretKey::new(FrElement::new(U256::from(
+            "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+        )))
+        .unwrap()
+    }
+
+    const REFERENCE_MSG: [u8; 32] = [0; 32];
+
+    #[test]
+    fn key_gen_matches_reference_implementation() {
+        let secret_key =
+            SecretKey::key_gen(b"hello world (it's a secret!) very secret stuff", b"").unwrap();
+        assert_eq!(
+            secret_key.value().representative(),
+            U256::from("4a73baed5cb75109cc9e063a0ea0009c2d80f7b7f5ea6cc48a223b0f9e257f7d")
+        );
+    }
+
+    #[test]
+    fn key_gen_rejects_short_key_material() {
+        assert!(matches!(
+            SecretKey::key_gen(&[0; 31], b""),
+            Err(BlsError::ShortKeyMaterial)
+        ));
+    }
+
+    #[test]
+    fn zero_is_not_a_secret_key() {
+        assert!(matches!(
+            SecretKey::new(FrElement::zero()),
+            Err(BlsError::ZeroSecretKey)
+        ));
+    }
+
+    #[test]
+    fn debug_output_of_secret_keys_is_redacted() {
+        assert_eq!(format!("{:?}", test_secret_key(1)), "SecretKey(..)");
+    }
+
+    fn sign_and_verify<V: IsBlsVariant>()
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+        Signature<V>: MultiplyBySecretKey,
+    {
+        let secret_key = test_secret_key(1);
+        let public_key = BlsSignatureScheme::<V>::sk_to_pk(&secret_key);
+        let signature = BlsSignatureScheme::<V>::sign(&secret_key, b"message");
+        assert!(BlsSignatureScheme::<V>::verify(
+            &public_key,
+            b"message",
+            &signature
+        ));
+        assert!(!BlsSignatureScheme::<V>::verify(
+            &public_key,
+            b"another message",
+            &signature
+        ));
+        let another_public_key = BlsSignatureScheme::<V>::sk_to_pk(&test_secret_key(2));
+        assert!(!BlsSignatureScheme::<V>::verify(
+            &another_public_key,
+            b"message",
+            &signature
+        ));
+    }
+
+    fn aggregate_and_verify<V: IsBlsVariant>()
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+        Signature<V>: MultiplyBySecretKey,
+    {
+        let secret_keys = [test_secret_key(1), test_secret_key(2)];
+        let public_keys: Vec<_> = secret_keys
+            .iter()
+            .map(BlsSignatureScheme::<V>::sk_to_pk)
+            .collect();
+        let msgs: [&[u8]; 2] = [b"first message", b"second message"];
+        let signatures: Vec<_> = secret_keys
+            .iter()
+            .zip(msgs)
+            .map(|(secret_key, msg)| BlsSignatureScheme::<V>::sign(secret_key, msg))
+            .collect();
+        let signature = BlsSignatureScheme::<V>::aggregate(&signatures).unwrap();
+        assert!(BlsSignatureScheme::<V>::aggregate_verify(
+            &public_keys,
+            &msgs,
+            &signature
+        ));
+        assert!(!BlsSignatureScheme::<V>::aggregate_verify(
+            &public_keys,
+            &[msgs[1], msgs[0]],
+            &signature
+        ));
+
+        let signatures: Vec<_> = secret_keys
+            .iter()
+            .map(|secret_key| BlsSignatureScheme::<V>::sign(secret_key, msgs[0]))
+            .collect();
+        let signature = BlsSignatureScheme::<V>::aggregate(&signatures).unwrap();
+        assert!(BlsSignatureScheme::<V>::fast_aggregate_verify(
+            &public_keys,
+            msgs[0],
+            &signature
+        ));
+        assert!(!BlsSignatureScheme::<V>::fast_aggregate_verify(
+            &public_keys[..1],
+            msgs[0],
+            &signature
+        ));
+        assert!(BlsSignatureScheme::<V>::aggregate_verify(
+            &public_keys,
+            &[msgs[0], msgs[0]],
+            &signature
+        ));
+    }
+
+    fn prove_possession<V: IsBlsVariant>()
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+        Signature<V>: MultiplyBySecretKey,
+    {
+        let secret_key = test_secret_key(1);
+        let public_key = BlsSignatureScheme::<V>::sk_to_pk(&secret_key);
+        let proof = BlsSignatureScheme::<V>::pop_prove(&secret_key);
+        assert!(BlsSignatureScheme::<V>::pop_verify(&public_key, &proof));
+        let another_public_key = BlsSignatureScheme::<V>::sk_to_pk(&test_secret_key(2));
+        assert!(!BlsSignatureScheme::<V>::pop_verify(
+            &another_public_key,
+            &proof
+        ));
+        // Proofs are domain separated from signatures of the encoded public key.
+        let public_key_bytes = V::public_key_to_bytes(&public_key);
+        assert!(!BlsSignatureScheme::<V>::verify(
+            &public_key,
+            &public_key_bytes,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn min_pk_signatures_verify() {
+        sign_and_verify::<MinPk>();
+    }
+
+    #[test]
+    fn min_sig_signatures_verify() {
+        sign_and_verify::<MinSig>();
+    }
+
+    #[test]
+    fn min_pk_aggregate_signatures_verify() {
+        aggregate_and_verify::<MinPk>();
+    }
+
+    #[test]
+    fn min_sig_aggregate_signatures_verify() {
+        aggregate_and_verify::<MinSig>();
+    }
+
+    #[test]
+    fn min_pk_proofs_of_possession_verify() {
+        prove_possession::<MinPk>();
+    }
+
+    #[test]
+    fn min_sig_proofs_of_possession_verify() {
+        prove_possession::<MinSig>();
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn constant_time_signing_matches_wnaf<V: IsBlsVariant>()
+    where
+        PublicKey<V>: MultiplyBySecretKey,
+        Signature<V>: MultiplyBySecretKey,
+    {
+        let secret_key = test_secret_key(1);
+        let scalar = secret_key.value().representative();
+        assert!(
+            BlsSignatureScheme::<V>::sk_to_pk(&secret_key)
+                == V::PublicKeyCurve::generator().operate_with_self_wnaf(scalar)
+        );
+        assert!(
+            BlsSignatureScheme::<V>::sign(&secret_key, b"message")
+                == V::hash_to_signature_group(b"message", V::SIGNATURE_DST)
+                    .operate_with_self_wnaf(scalar)
+        );
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn min_pk_constant_time_signing_matches_wnaf() {
+        constant_time_signing_matches_wnaf::<MinPk>();
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn min_sig_constant_time_signing_matches_wnaf() {
+        constant_time_signing_matches_wnaf::<MinSig>();
+    }
+
+    #[test]
+    fn min_pk_signature_matches_reference_implementation() {
+        let secret_key = reference_secret_key();
+        assert_eq!(
+            to_hex(&compress_g1_point(&MinPkBls::sk_to_pk(&secret_key))),
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20f\
+             d6e10c1b77654d067c0618f6e5a7f79a"
+        );
+        assert_eq!(
+            to_hex(&compress_g2_point(&MinPkBls::sign(
+                &secret_key,
+                &REFERENCE_MSG
+            ))),
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6\
+             076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24\
+             802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+        );
+    }
+
+    #[test]
+    fn min_sig_signature_matches_reference_implementation() {
+        let secret_key = reference_secret_key();
+        assert_eq!(
+            to_hex(&compress_g2_point(&MinSigBls::sk_to_pk(&secret_key))),
+            "ac400b70f6f8cd35648f5c126cce5417f3be4d8eefbd42ceb4286a14df7e0313\
+             5313fe5845e3a575faab3e8b949d248814856c22d8cdb2967c720e963eedc999\
+             e738373b14172f06fc915769d3cc5ab7ae0a1b9c38f48b5585fb09d4bd2733bb"
+        );
+        assert_eq!(
+            to_hex(&compress_g1_point(&MinSigBls::sign(
+                &secret_key,
+                &REFERENCE_MSG
+            ))),
+            "950998b098aeab7dddcef4916123247ae9f48ca4f7f0df3a487d244c26af107e\
+             4de324bd1181554122cfb251ed0b213f"
+        );
+    }
+
+    #[test]
+    fn aggregate_of_no_signatures_is_an_error() {
+        assert_eq!(MinPkBls::aggregate(&[]), Err(BlsError::EmptyAggregate));
+    }
+
+    #[test]
+    fn neutral_element_is_not_a_valid_public_key() {
+        let signature = MinPkBls::sign(&test_secret_key(1), b"message");
+        assert!(!MinPkBls::verify(
+            &PublicKey::<MinPk>::neutral_element(),
+            b"message",
+            &signature
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 use const_random::const_random;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::u64_prime_field::U64FieldElement;
+use lambdaworks_math::traits::RandomSample;
 
 // Mersenne prime numbers
 // https://www.math.utah.edu/~pa/math/mersenne.html
@@ -16,43 +16,43 @@ fn u64_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("u64");
 
     group.bench_function("add", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
-        let y: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
+        let y = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x) + black_box(y));
     });
 
     group.bench_function("mul", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
-        let y: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
+        let y = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x) * black_box(y));
     });
 
     group.bench_function("pow", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         let y: u64 = 5;
         bench.iter(|| black_box(x).pow(black_box(y)));
     });
 
     group.bench_function("sub", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
-        let y: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
+        let y = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x) - black_box(y));
     });
 
     group.bench_function("inv", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x).inv());
     });
 
     group.bench_function("div", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
-        let y: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
+        let y = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x) / black_box(y));
     });
 
     group.bench_function("eq", |bench| {
-        let x: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
-        let y: U64FieldElement<MODULUS> = FieldElement::new(rand::random());
+        let x = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
+        let y = U64FieldElement::<MODULUS>::random(&mut rand::thread_rng());
         bench.iter(|| black_box(x) == black_box(y));
     });
 }
@@ -0,0 +1,201 @@
+use crate::{
+    cyclic_group::IsGroup, field::traits::IsField, unsigned_integer::element::UnsignedInteger,
+};
+use std::ops::{BitAnd, BitOr, Not};
+
+/// A boolean stored as a mask of 64 bits that are either all zeros or all ones,
+/// so that it can be combined with data without branching on its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Choice(u64);
+
+impl Choice {
+    /// Creates a `Choice` from a `bit` that must be either 0 or 1.
+    pub fn from_bit(bit: u64) -> Self {
+        debug_assert!(bit <= 1, "The bit must be either 0 or 1.");
+        Self(bit.wrapping_neg())
+    }
+
+    /// Returns the mask of all zeros or all ones of the choice.
+    pub fn mask(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(value: bool) -> Self {
+        Self::from_bit(value as u64)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 != 0
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Choice {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Choice) -> Choice {
+        Choice(self.0 | rhs.0)
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Choice {
+        Choice(!self.0)
+    }
+}
+
+/// Trait for types whose values can be selected without branching on the choice.
+pub trait ConditionallySelectable: Sized {
+    /// Returns `a` if `choice` is false and `b` if it is true.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Replaces `self` by `other` if `choice` is true.
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    /// Swaps `a` and `b` if `choice` is true.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let new_a = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+    }
+}
+
+/// Trait for types whose values can be compared in time independent of them.
+pub trait ConstantTimeEq {
+    /// Returns whether `self` and `other` are equal.
+    fn ct_eq(&self, other: &Self) -> Choice;
+}
+
+impl ConditionallySelectable for u64 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        a ^ ((a ^ b) & choice.mask())
+    }
+}
+
+impl<const NUM_LIMBS: usize> ConditionallySelectable for UnsignedInteger<NUM_LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            limbs: <[u64; NUM_LIMBS]>::conditional_select(&a.limbs, &b.limbs, choice),
+        }
+    }
+}
+
+impl<const NUM_LIMBS: usize> ConstantTimeEq for UnsignedInteger<NUM_LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let difference = self
+            .limbs
+            .iter()
+            .zip(other.limbs)
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+        // The most significant bit of `x | -x` is set if and only if `x` is nonzero.
+        Choice::from_bit(((difference | difference.wrapping_neg()) >> 63) ^ 1)
+    }
+}
+
+impl<T: ConditionallySelectable, const N: usize> ConditionallySelectable for [T; N] {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        std::array::from_fn(|i| T::conditional_select(&a[i], &b[i], choice))
+    }
+}
+
+impl<T: ConstantTimeEq, const N: usize> ConstantTimeEq for [T; N] {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.iter()
+            .zip(other)
+            .fold(Choice::from(true), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
+
+/// Marker for fields whose addition, subtraction, negation and multiplication
+/// run in time independent of the operands when the `constant-time` feature is enabled.
+/// Only the Montgomery backed prime fields and their extensions qualify.
+pub trait IsConstantTimeField: IsField {}
+
+/// Trait for groups whose operation can be computed without branches
+/// depending on the operands, to handle secret values.
+pub trait IsConstantTimeGroup: IsGroup + ConditionallySelectable {
+    /// Applies the group operation between `self` and `other` with a formula
+    /// that has no exceptional cases.
+    fn operate_with_constant_time(&self, other: &Self) -> Self;
+
+    /// Applies the group operation `exponent` times with itself.
+    /// Uses the Montgomery ladder over all the bits of `exponent`, so that the
+    /// sequence of operations does not depend on its value.
+    fn operate_with_self_constant_time<const NUM_LIMBS: usize>(
+        &self,
+        exponent: &UnsignedInteger<NUM_LIMBS>,
+    ) -> Self {
+        // Invariant: `r1 = r0 + self`.
+        let mut r0 = Self::neutral_element();
+        let mut r1 = self.clone();
+        for i in (0..64 * NUM_LIMBS).rev() {
+            let bit = Choice::from_bit((exponent.limbs[NUM_LIMBS - 1 - i / 64] >> (i % 64)) & 1);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0.operate_with_constant_time(&r1);
+            r0 = r0.operate_with_constant_time(&r0);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned_integer::element::U384;
+
+    #[test]
+    fn choice_operations_work() {
+        let (yes, no) = (Choice::from(true), Choice::from(false));
+        assert_eq!(yes.mask(), u64::MAX);
+        assert_eq!(no.mask(), 0);
+        assert_eq!(yes & no, no);
+        assert_eq!(yes | no, yes);
+        assert_eq!(!no, yes);
+        assert!(bool::from(yes));
+        assert!(!bool::from(no));
+    }
+
+    #[test]
+    fn conditional_select_works() {
+        let a = U384::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf");
+        let b = U384::from_u64(5);
+        assert_eq!(U384::conditional_select(&a, &b, Choice::from(false)), a);
+        assert_eq!(U384::conditional_select(&a, &b, Choice::from(true)), b);
+        assert_eq!(u64::conditional_select(&3, &7, Choice::from(true)), 7);
+    }
+
+    #[test]
+    fn conditional_swap_works() {
+        let (mut a, mut b) = (U384::from_u64(2), U384::from_u64(9));
+        U384::conditional_swap(&mut a, &mut b, Choice::from(false));
+        assert_eq!((a, b), (U384::from_u64(2), U384::from_u64(9)));
+        U384::conditional_swap(&mut a, &mut b, Choice::from(true));
+        assert_eq!((a, b), (U384::from_u64(9), U384::from_u64(2)));
+    }
+
+    #[test]
+    fn ct_eq_works() {
+        let a = U384::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf");
+        let b = U384::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512be");
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(bool::from([a, b].ct_eq(&[a, b])));
+        assert!(!bool::from([a, b].ct_eq(&[a, a])));
+    }
+}
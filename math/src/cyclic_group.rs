@@ -1,3 +1,5 @@
+use crate::unsigned_integer::traits::IsUnsignedInteger;
+
 pub trait IsGroup: Clone + PartialEq + Eq {
     /// Returns the neutral element of the group. The equality
     /// `neutral_element().operate_with(g) == g` must hold
@@ -31,3 +33,144 @@ pub trait IsGroup: Clone + PartialEq + Eq {
     /// the notation of the particular group.
     fn operate_with(&self, other: &Self) -> Self;
 }
+
+/// A group where the inverse of an element is cheap to compute, as in the
+/// groups of points of an elliptic curve. It allows scalar multiplication
+/// with signed digits.
+pub trait IsGroupWithNegation: IsGroup {
+    /// Returns the inverse of `self` with respect to the group operation.
+    fn neg(&self) -> Self;
+
+    /// Applies the group operation `exponent` times with itself, for an
+    /// exponent of any size such as `U256` or `U384`.
+    /// Uses the width-w non-adjacent form of `exponent` and a table with
+    /// the odd multiples of `self`, so it takes a doubling per bit and
+    /// about one operation every `w + 1` bits.
+    fn operate_with_self_wnaf<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        let (digits, _) = wnaf(exponent);
+        let Some(max_digit) = digits.iter().map(|digit| digit.unsigned_abs()).max() else {
+            return Self::neutral_element();
+        };
+
+        // `odd_multiples[i]` is `(2 * i + 1)` times `self`.
+        let double = self.operate_with(self);
+        let mut odd_multiples = vec![self.clone()];
+        for i in 1..=(max_digit as usize / 2) {
+            odd_multiples.push(odd_multiples[i - 1].operate_with(&double));
+        }
+
+        let mut result = Self::neutral_element();
+        for digit in digits.iter().rev() {
+            result = result.operate_with(&result);
+            let multiple = &odd_multiples[digit.unsigned_abs() as usize / 2];
+            if *digit > 0 {
+                result = result.operate_with(multiple);
+            } else if *digit < 0 {
+                result = result.operate_with(&multiple.neg());
+            }
+        }
+        result
+    }
+}
+
+/// Returns the digits of the width-w non-adjacent form of `exponent`, from the
+/// least to the most significant. Nonzero digits are odd, smaller than `2^(w - 1)`
+/// in absolute value and followed by at least `w - 1` zeros.
+/// The width is chosen to minimize the number of group operations, and is returned
+/// along with the digits.
+fn wnaf<T: IsUnsignedInteger>(mut exponent: T) -> (Vec<i64>, usize) {
+    let mut bits = Vec::new();
+    while exponent > T::from(0) {
+        bits.push((exponent & T::from(1) == T::from(1)) as u8);
+        exponent = exponent >> 1;
+    }
+    // A table of `2^(w - 2)` entries saves about `bits / (w + 1)` operations.
+    let width = (2..=8)
+        .min_by_key(|w| (1 << (w - 2)) + bits.len() / (w + 1))
+        .unwrap();
+
+    let mut digits = Vec::with_capacity(bits.len() + 1);
+    let mut i = 0;
+    while i < bits.len() {
+        let mut digit = 0;
+        if bits[i] == 1 {
+            let end = bits.len().min(i + width);
+            let window = bits[i..end]
+                .iter()
+                .rev()
+                .fold(0, |acc, bit| 2 * acc + *bit as i64);
+            bits[i..end].fill(0);
+            digit = window;
+            if window >= 1 << (width - 1) {
+                // Subtracting the negative digit carries a one into the position `i + width`.
+                digit -= 1 << width;
+                let mut j = i + width;
+                while j < bits.len() && bits[j] == 1 {
+                    bits[j] = 0;
+                    j += 1;
+                }
+                if j < bits.len() {
+                    bits[j] = 1;
+                } else {
+                    bits.resize(j, 0);
+                    bits.push(1);
+                }
+            }
+        }
+        digits.push(digit);
+        i += 1;
+    }
+    (digits, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::u64_prime_field::U64FieldElement;
+    use crate::unsigned_integer::element::U384;
+    use proptest::prelude::*;
+
+    fn evaluate(digits: &[i64]) -> i128 {
+        digits
+            .iter()
+            .rev()
+            .fold(0, |acc, digit| 2 * acc + *digit as i128)
+    }
+
+    #[test]
+    fn wnaf_of_zero_has_no_digits() {
+        assert!(wnaf(0_u64).0.is_empty());
+        assert_eq!(
+            U64FieldElement::<23>::from(4).operate_with_self_wnaf(0_u64),
+            U64FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn wnaf_works_with_big_unsigned_integers() {
+        let g = U64FieldElement::<1000000007>::from(3);
+        let exponent = U384::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf");
+        let expected =
+            U64FieldElement::from(exponent.rem(&U384::from_u64(1000000007)).limbs[5]) * g;
+        assert_eq!(g.operate_with_self_wnaf(exponent), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn wnaf_digits_represent_the_exponent(exponent in any::<u64>()) {
+            let (digits, _) = wnaf(exponent);
+            prop_assert_eq!(evaluate(&digits), exponent as i128);
+            for (i, digit) in digits.iter().enumerate().filter(|(_, digit)| **digit != 0) {
+                prop_assert_eq!(digit.rem_euclid(2), 1);
+                let next = &digits[i + 1..digits.len().min(i + 2)];
+                prop_assert!(next.iter().all(|digit| *digit == 0));
+            }
+        }
+
+        #[test]
+        fn operate_with_self_wnaf_matches_operate_with_self(exponent in any::<u128>()) {
+            let g = U64FieldElement::<1000000007>::from(5);
+            prop_assert_eq!(g.operate_with_self_wnaf(exponent), g.operate_with_self(exponent));
+        }
+    }
+}
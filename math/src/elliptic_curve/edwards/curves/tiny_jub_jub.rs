@@ -6,21 +6,28 @@ use crate::{
     field::{element::FieldElement, fields::u64_prime_field::U64PrimeField},
 };
 
-/// Taken from moonmath manual page 97
+/// Taken from moonmath manual page 97.
+/// The curve has 20 points and its generator spans the subgroup of order 5.
 #[derive(Debug, Clone)]
 pub struct TinyJubJubEdwards;
 
 impl IsEllipticCurve for TinyJubJubEdwards {
     type BaseField = U64PrimeField<13>;
+    type ScalarField = U64PrimeField<5>;
+    type Cofactor = u64;
     type PointRepresentation = EdwardsProjectivePoint<Self>;
 
     fn generator() -> Self::PointRepresentation {
         Self::PointRepresentation::new([
-            FieldElement::from(8),
-            FieldElement::from(5),
+            FieldElement::from(6),
+            FieldElement::from(9),
             FieldElement::one(),
         ])
     }
+
+    fn cofactor() -> u64 {
+        4
+    }
 }
 
 impl IsEdwards for TinyJubJubEdwards {
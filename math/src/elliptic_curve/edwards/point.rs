@@ -1,11 +1,20 @@
+#[cfg(feature = "constant-time")]
+use crate::constant_time::{
+    Choice, ConditionallySelectable, IsConstantTimeField, IsConstantTimeGroup,
+};
 use crate::{
-    cyclic_group::IsGroup,
+    cyclic_group::{IsGroup, IsGroupWithNegation},
     elliptic_curve::{
         point::ProjectivePoint,
         traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
     },
-    field::element::FieldElement,
+    field::{element::FieldElement, errors::FieldError},
+    msm::msm,
+    traits::RandomSample,
+    unsigned_integer::element::UnsignedInteger,
 };
+use rand::Rng;
+use std::ops::Mul;
 
 use super::traits::IsEdwards;
 
@@ -44,6 +53,25 @@ impl<E: IsEllipticCurve> EdwardsProjectivePoint<E> {
     pub fn to_affine(&self) -> Self {
         Self(self.0.to_affine())
     }
+
+    /// Creates the same point in affine coordinates, or returns an error
+    /// if `self` is the point at infinity.
+    pub fn try_to_affine(&self) -> Result<Self, FieldError> {
+        self.0.try_to_affine().map(Self)
+    }
+
+    /// Creates the same points in affine coordinates, using a single inversion
+    /// for all of them.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<Self> {
+        let points: Vec<_> = points
+            .iter()
+            .map(|point| ProjectivePoint::new(point.coordinates().clone()))
+            .collect();
+        ProjectivePoint::batch_to_affine(&points)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
 }
 
 impl<E: IsEllipticCurve> PartialEq for EdwardsProjectivePoint<E> {
@@ -95,16 +123,110 @@ impl<E: IsEdwards> IsGroup for EdwardsProjectivePoint<E> {
     }
 }
 
+impl<E: IsEdwards> IsGroupWithNegation for EdwardsProjectivePoint<E> {
+    /// Returns the additive inverse of `self`, which negates the `x` coordinate.
+    fn neg(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        Self::new([-x, y.clone(), z.clone()])
+    }
+}
+
+/// Samples a point of the subgroup generated by `E::generator()` as a random multiple
+/// of the generator. The scalar has 512 bits, so its reduction modulo the order of the
+/// subgroup is uniform up to a negligible bias.
+impl<E: IsEdwards<PointRepresentation = Self>> RandomSample for EdwardsProjectivePoint<E> {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let scalar = UnsignedInteger::<8>::random(rng);
+        msm(&[scalar], &[E::generator()])
+    }
+}
+
+/// Multiplies the point by a scalar of the scalar field of its curve.
+impl<E: IsEdwards> Mul<&FieldElement<E::ScalarField>> for &EdwardsProjectivePoint<E> {
+    type Output = EdwardsProjectivePoint<E>;
+
+    fn mul(self, scalar: &FieldElement<E::ScalarField>) -> Self::Output {
+        self.operate_with_self_wnaf(scalar.representative())
+    }
+}
+
+impl<E: IsEdwards> Mul<FieldElement<E::ScalarField>> for EdwardsProjectivePoint<E> {
+    type Output = EdwardsProjectivePoint<E>;
+
+    fn mul(self, scalar: FieldElement<E::ScalarField>) -> Self::Output {
+        &self * &scalar
+    }
+}
+
+impl<E: IsEdwards> Mul<&FieldElement<E::ScalarField>> for EdwardsProjectivePoint<E> {
+    type Output = EdwardsProjectivePoint<E>;
+
+    fn mul(self, scalar: &FieldElement<E::ScalarField>) -> Self::Output {
+        &self * scalar
+    }
+}
+
+impl<E: IsEdwards> Mul<FieldElement<E::ScalarField>> for &EdwardsProjectivePoint<E> {
+    type Output = EdwardsProjectivePoint<E>;
+
+    fn mul(self, scalar: FieldElement<E::ScalarField>) -> Self::Output {
+        self * &scalar
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<E: IsEllipticCurve> ConditionallySelectable for EdwardsProjectivePoint<E>
+where
+    FieldElement<E::BaseField>: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(ConditionallySelectable::conditional_select(
+            a.coordinates(),
+            b.coordinates(),
+            choice,
+        ))
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<E: IsEdwards> IsConstantTimeGroup for EdwardsProjectivePoint<E>
+where
+    E::BaseField: IsConstantTimeField,
+    FieldElement<E::BaseField>: ConditionallySelectable,
+{
+    /// Computes the addition of `self` and `other` in projective coordinates with
+    /// the EFD formula "add-2008-bbjlp". It is complete when `a` is a square and
+    /// `d` is not, which holds for the curves of this library.
+    fn operate_with_constant_time(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+
+        let a = z1 * z2;
+        let b = a.pow(2_u16);
+        let c = x1 * x2;
+        let d = y1 * y2;
+        let e = E::d() * &c * &d;
+        let f = &b - &e;
+        let g = b + e;
+        let x3 = &a * &f * ((x1 + y1) * (x2 + y2) - &c - &d);
+        let y3 = a * &g * (d - E::a() * c);
+        let z3 = f * g;
+        Self::new([x3, y3, z3])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        cyclic_group::IsGroup,
+        cyclic_group::{IsGroup, IsGroupWithNegation},
         elliptic_curve::{
             edwards::{curves::tiny_jub_jub::TinyJubJubEdwards, point::EdwardsProjectivePoint},
             traits::{EllipticCurveError, IsEllipticCurve},
         },
         field::element::FieldElement,
+        traits::RandomSample,
     };
+    use rand::{rngs::StdRng, SeedableRng};
 
     fn create_point(x: u64, y: u64) -> EdwardsProjectivePoint<TinyJubJubEdwards> {
         TinyJubJubEdwards::create_point_from_affine(FieldElement::from(x), FieldElement::from(y))
@@ -177,4 +299,134 @@ mod tests {
         assert_eq!(g.operate_with_self(19), create_point(1, 11));
         assert_eq!(g.operate_with_self(20), create_point(0, 1));
     }
+
+    #[test]
+    fn random_points_are_multiples_of_the_generator() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let multiples: Vec<_> = (0..10)
+            .map(|k| TinyJubJubEdwards::generator().operate_with_self(k))
+            .collect();
+        for _ in 0..50 {
+            let p = EdwardsProjectivePoint::<TinyJubJubEdwards>::random(&mut rng);
+            assert!(multiples.contains(&p));
+        }
+    }
+
+    #[test]
+    fn the_generator_spans_the_subgroup_of_order_five() {
+        let g = TinyJubJubEdwards::generator();
+        assert_eq!(
+            g,
+            create_point(12, 11).operate_with_self_wnaf(TinyJubJubEdwards::cofactor())
+        );
+        assert!(!g.is_neutral_element());
+        assert!(g.operate_with_self(5).is_neutral_element());
+    }
+
+    #[test]
+    fn multiplication_by_scalar_field_elements_works() {
+        let g = TinyJubJubEdwards::generator();
+        for k in 0..5 {
+            assert_eq!(&g * FieldElement::from(k), g.operate_with_self(k as u128));
+        }
+        assert_eq!(g.clone() * -FieldElement::one(), g.neg());
+    }
+
+    #[test]
+    fn batch_to_affine_matches_to_affine() {
+        let g = TinyJubJubEdwards::generator();
+        let points = [
+            EdwardsProjectivePoint::new([
+                FieldElement::from(5 * 3),
+                FieldElement::from(5 * 3),
+                FieldElement::from(3),
+            ]),
+            g.clone(),
+            EdwardsProjectivePoint::new([
+                FieldElement::from(8 * 7),
+                FieldElement::from(5 * 7),
+                FieldElement::from(7),
+            ]),
+        ];
+        let affine_points = EdwardsProjectivePoint::batch_to_affine(&points);
+        for (point, affine_point) in points.iter().zip(affine_points) {
+            assert_eq!(affine_point.coordinates(), point.to_affine().coordinates());
+            assert_eq!(affine_point.z(), &FieldElement::one());
+        }
+    }
+
+    /// TinyJubJub over a Montgomery backed copy of its base field,
+    /// since only those fields have branch-free operations.
+    #[cfg(feature = "constant-time")]
+    mod constant_time {
+        use crate::{
+            constant_time::IsConstantTimeGroup,
+            cyclic_group::IsGroup,
+            elliptic_curve::{
+                edwards::{point::EdwardsProjectivePoint, traits::IsEdwards},
+                traits::IsEllipticCurve,
+            },
+            field::{
+                element::FieldElement,
+                fields::{
+                    montgomery_backed_prime_fields::{IsMontgomeryConfiguration, U256PrimeField},
+                    u64_prime_field::U64PrimeField,
+                },
+            },
+            unsigned_integer::element::U256,
+        };
+
+        #[derive(Clone, Debug)]
+        struct MontgomeryConfig13;
+        impl IsMontgomeryConfiguration<4> for MontgomeryConfig13 {
+            const MODULUS: U256 = U256::from_u64(13);
+        }
+
+        #[derive(Clone, Debug)]
+        struct MontgomeryTinyJubJub;
+
+        impl IsEllipticCurve for MontgomeryTinyJubJub {
+            type BaseField = U256PrimeField<MontgomeryConfig13>;
+            type ScalarField = U64PrimeField<5>;
+            type Cofactor = u64;
+            type PointRepresentation = EdwardsProjectivePoint<Self>;
+
+            fn generator() -> Self::PointRepresentation {
+                Self::PointRepresentation::new([
+                    FieldElement::from(6),
+                    FieldElement::from(9),
+                    FieldElement::one(),
+                ])
+            }
+
+            fn cofactor() -> u64 {
+                4
+            }
+        }
+
+        impl IsEdwards for MontgomeryTinyJubJub {
+            fn a() -> FieldElement<Self::BaseField> {
+                FieldElement::from(3)
+            }
+
+            fn d() -> FieldElement<Self::BaseField> {
+                FieldElement::from(8)
+            }
+        }
+
+        #[test]
+        fn operate_with_self_constant_time_matches_operate_with_self() {
+            let g = MontgomeryTinyJubJub::create_point_from_affine(
+                FieldElement::from(12),
+                FieldElement::from(11),
+            )
+            .unwrap();
+            for k in 0..20 {
+                assert_eq!(
+                    g.operate_with_self_constant_time(&U256::from_u64(k)),
+                    g.operate_with_self(k as u128)
+                );
+            }
+        }
+    }
 }
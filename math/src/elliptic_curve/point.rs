@@ -1,5 +1,6 @@
 use crate::elliptic_curve::traits::IsEllipticCurve;
 use crate::field::element::FieldElement;
+use crate::field::errors::FieldError;
 use std::fmt::Debug;
 
 /// Represents an elliptic curve point using the projective short Weierstrass form:
@@ -40,9 +41,47 @@ impl<E: IsEllipticCurve> ProjectivePoint<E> {
     /// returns [x / z: y / z: 1] where `self` is [x: y: z].
     /// Panics if `self` is the point at infinity.
     pub fn to_affine(&self) -> Self {
+        self.try_to_affine()
+            .expect("the point at infinity has no affine coordinates")
+    }
+
+    /// Creates the same point in affine coordinates, or returns an error
+    /// if `self` is the point at infinity.
+    pub fn try_to_affine(&self) -> Result<Self, FieldError> {
         let [x, y, z] = self.coordinates();
-        assert_ne!(z, &FieldElement::zero());
-        ProjectivePoint::new([x / z, y / z, FieldElement::one()])
+        let z_inv = z.try_inv()?;
+        Ok(ProjectivePoint::new([
+            x * &z_inv,
+            y * z_inv,
+            FieldElement::one(),
+        ]))
+    }
+
+    /// Creates the same points in affine coordinates, using a single inversion
+    /// for all of them. Points with `z = 0` are returned unchanged.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<Self> {
+        let zero = FieldElement::zero();
+        let mut z_inverses: Vec<_> = points
+            .iter()
+            .map(|point| point.z().clone())
+            .filter(|z| *z != zero)
+            .collect();
+        FieldElement::inplace_batch_inverse(&mut z_inverses)
+            .expect("the zero coordinates have been filtered out");
+
+        let mut z_inverses = z_inverses.into_iter();
+        points
+            .iter()
+            .map(|point| {
+                let [x, y, z] = point.coordinates();
+                if *z == zero {
+                    ProjectivePoint::new(point.coordinates().clone())
+                } else {
+                    let z_inverse = z_inverses.next().unwrap();
+                    ProjectivePoint::new([x * &z_inverse, y * z_inverse, FieldElement::one()])
+                }
+            })
+            .collect()
     }
 }
 
@@ -58,7 +97,7 @@ impl<E: IsEllipticCurve> Eq for ProjectivePoint<E> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::cyclic_group::IsGroup;
+    use crate::cyclic_group::{IsGroup, IsGroupWithNegation};
     use crate::elliptic_curve::short_weierstrass::curves::test_curve_1::{
         TestCurve1, TestCurveQuadraticNonResidue, TEST_CURVE_1_MAIN_SUBGROUP_ORDER,
         TEST_CURVE_1_PRIME_FIELD_ORDER,
@@ -66,7 +105,9 @@ mod tests {
     use crate::elliptic_curve::short_weierstrass::curves::test_curve_2::TestCurve2;
     use crate::elliptic_curve::short_weierstrass::pairings::tate::tate_pairing;
     use crate::elliptic_curve::short_weierstrass::pairings::weil::weil_pairing;
+    use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
     use crate::field::element::FieldElement;
+    use crate::field::errors::FieldError;
     use crate::field::fields::u64_prime_field::U64FieldElement;
     use crate::unsigned_integer::element::U384;
     //use crate::elliptic_curve::curves::test_curve_2::TestCurve2;
@@ -105,10 +146,38 @@ mod tests {
         assert_eq!(g.operate_with(&g).operate_with(&g), g.operate_with_self(3));
     }
 
+    #[test]
+    fn batch_to_affine_matches_to_affine_and_keeps_the_point_at_infinity() {
+        let g = TestCurve1::generator();
+        let points = [
+            g.operate_with_self(2),
+            g.operate_with_self_wnaf(TEST_CURVE_1_MAIN_SUBGROUP_ORDER),
+            g.operate_with_self(3),
+            g.clone(),
+        ];
+        let affine_points = ShortWeierstrassProjectivePoint::batch_to_affine(&points);
+        assert_eq!(affine_points.len(), points.len());
+        for (point, affine_point) in points.iter().zip(&affine_points) {
+            if point.is_neutral_element() {
+                assert_eq!(affine_point.coordinates(), point.coordinates());
+            } else {
+                assert_eq!(affine_point.coordinates(), point.to_affine().coordinates());
+            }
+        }
+    }
+
+    #[test]
+    fn try_to_affine_of_the_point_at_infinity_is_an_error() {
+        let g = TestCurve1::generator();
+        let infinity = g.operate_with_self_wnaf(TEST_CURVE_1_MAIN_SUBGROUP_ORDER);
+        assert_eq!(infinity.try_to_affine(), Err(FieldError::DivisionByZero));
+        assert_eq!(g.try_to_affine(), Ok(g.to_affine()));
+    }
+
     #[test]
     fn operate_with_self_works_2() {
         let mut point_1 = TestCurve1::generator();
-        point_1 = point_1.operate_with_self(TEST_CURVE_1_MAIN_SUBGROUP_ORDER as u128);
+        point_1 = point_1.operate_with_self_wnaf(TEST_CURVE_1_MAIN_SUBGROUP_ORDER);
         assert!(point_1.is_neutral_element());
     }
 
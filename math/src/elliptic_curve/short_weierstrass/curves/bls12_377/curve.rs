@@ -1,16 +1,27 @@
-use super::field_extension::BLS12377PrimeField;
+use super::{default_types::FrField, field_extension::BLS12377PrimeField};
+use crate::cyclic_group::IsGroupWithNegation;
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
 use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::U256;
 use crate::{
     elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
 };
 
+/// The parameter `x` that defines the BLS12-377 curve.
+pub const X: u64 = 0x8508c00000000001;
+
+/// Order `r` of the subgroup G1.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from("12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001");
+
 /// The description of the curve.
 #[derive(Clone, Debug)]
 pub struct BLS12377Curve;
 
 impl IsEllipticCurve for BLS12377Curve {
     type BaseField = BLS12377PrimeField;
+    type ScalarField = FrField;
+    type Cofactor = u128;
     type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
 
     fn generator() -> Self::PointRepresentation {
@@ -20,6 +31,34 @@ impl IsEllipticCurve for BLS12377Curve {
             FieldElement::one()
         ])
     }
+
+    /// `(x - 1)^2 / 3`, where `x` is the parameter of the curve.
+    fn cofactor() -> u128 {
+        0x170b5d44300000000000000000000000
+    }
+
+    /// Checks that `phi(p) == [-x^2]p`, where `x` is the parameter of the curve.
+    /// Taken from "Faster subgroup checks for BLS12-381" (https://eprint.iacr.org/2021/1130).
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.phi() == p.operate_with_self_wnaf((X as u128).pow(2)).neg()
+    }
+
+    /// Multiplies `p` by `x - 1`, which maps every point of the curve to the subgroup.
+    /// See "Fast and simple constant-time hashing to the BLS12-381 elliptic curve"
+    /// (https://eprint.iacr.org/2019/403), section 5.
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.operate_with_self_wnaf(X - 1)
+    }
+}
+
+impl ShortWeierstrassProjectivePoint<BLS12377Curve> {
+    /// The endomorphism `phi(x, y) = (beta * x, y)`, where `beta` is a
+    /// primitive cube root of unity in the base field.
+    pub fn phi(&self) -> Self {
+        let beta = FieldElement::<BLS12377PrimeField>::new_base("1ae3a4617c510eabc8756ba8f8c524eb8882a75cc9bc8e359064ee822fb5bffd1e945779fffffffffffffffffffffff");
+        let [x, y, z] = self.coordinates();
+        Self::new([x * beta, y.clone(), z.clone()])
+    }
 }
 
 impl IsShortWeierstrass for BLS12377Curve {
@@ -35,6 +74,7 @@ impl IsShortWeierstrass for BLS12377Curve {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic_curve::short_weierstrass::test_points;
     use crate::{
         cyclic_group::IsGroup, elliptic_curve::traits::EllipticCurveError,
         field::element::FieldElement,
@@ -93,4 +133,40 @@ mod tests {
         let g = BLS12377Curve::generator();
         assert_eq!(g.operate_with(&g).operate_with(&g), g.operate_with_self(3));
     }
+
+    fn point_not_in_the_subgroup() -> ShortWeierstrassProjectivePoint<BLS12377Curve> {
+        test_points::point_not_in_the_subgroup::<BLS12377Curve>(FEE::from, |x| x.sqrt())
+    }
+
+    #[test]
+    fn is_in_subgroup_matches_the_multiplication_by_the_order() {
+        let g = BLS12377Curve::generator();
+        let p = point_not_in_the_subgroup();
+        for point in [g.clone(), point_1(), p.clone(), p.operate_with_self(3)] {
+            assert_eq!(
+                BLS12377Curve::is_in_subgroup(&point),
+                point
+                    .operate_with_self_wnaf(SUBGROUP_ORDER)
+                    .is_neutral_element()
+            );
+        }
+        assert!(BLS12377Curve::is_in_subgroup(&g));
+        assert!(!BLS12377Curve::is_in_subgroup(&p));
+    }
+
+    #[test]
+    fn clear_cofactor_maps_points_to_the_subgroup() {
+        let p = point_not_in_the_subgroup();
+        assert!(BLS12377Curve::is_in_subgroup(
+            &BLS12377Curve::clear_cofactor(&p)
+        ));
+    }
+
+    #[test]
+    fn generator_has_the_order_of_the_subgroup() {
+        let g = BLS12377Curve::generator();
+        assert!(g
+            .operate_with_self_wnaf(SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
 }
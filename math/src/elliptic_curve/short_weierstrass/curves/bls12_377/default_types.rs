@@ -0,0 +1,48 @@
+use crate::field::{
+    element::FieldElement,
+    fields::montgomery_backed_prime_fields::{IsMontgomeryConfiguration, U256PrimeField},
+};
+use crate::unsigned_integer::element::U256;
+
+/// Configuration of the scalar field of BLS12-377, of order `r`.
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+impl IsMontgomeryConfiguration<4> for FrConfig {
+    const MODULUS: U256 =
+        U256::from("12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001");
+}
+
+/// The scalar field of BLS12-377. Its order is the order of the subgroup G1.
+pub type FrField = U256PrimeField<FrConfig>;
+pub type FrElement = FieldElement<FrField>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroupWithNegation,
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_377::curve::BLS12377Curve, traits::IsEllipticCurve,
+        },
+    };
+
+    #[test]
+    fn minus_one_times_the_generator_is_its_inverse() {
+        let g = BLS12377Curve::generator();
+        let minus_one = -FrElement::one();
+        assert_eq!(
+            g.operate_with_self_wnaf(minus_one.representative()),
+            g.neg()
+        );
+    }
+
+    #[test]
+    fn field_operations_work_with_the_scalar_field_order() {
+        let a = FrElement::from(123456789);
+        assert_eq!(&a * a.inv(), FrElement::one());
+        assert_eq!(
+            (-FrElement::one()).representative(),
+            FrConfig::MODULUS - U256::from_u64(1)
+        );
+    }
+}
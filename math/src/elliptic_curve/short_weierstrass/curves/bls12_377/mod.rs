@@ -1,2 +1,3 @@
 pub mod curve;
+pub mod default_types;
 pub mod field_extension;
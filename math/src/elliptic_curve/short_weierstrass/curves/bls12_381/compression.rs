@@ -0,0 +1,449 @@
+//! Serialization of BLS12-381 points following the ZCash format.
+//!
+//! Coordinates are encoded as 48 byte big-endian integers, and elements
+//! `c0 + c1 * u` of the quadratic extension as `c1 || c0`. The three most
+//! significant bits of the first byte are flags:
+//! - bit 7 is set for the compressed encoding, which only contains `x`,
+//! - bit 6 is set for the point at infinity, whose remaining bits are zero,
+//! - bit 5 is set in the compressed encoding if `y` is the lexicographically
+//!   largest of the two square roots `y` and `-y`.
+//!
+//! Points are checked to belong to the curve and to the subgroup of order `r`
+//! when decoded.
+
+use super::{
+    curve::BLS12381Curve,
+    field_extension::{BLS12381PrimeField, LevelOneField, BLS12381_PRIME_FIELD_ORDER},
+    twist::BLS12381TwistCurve,
+};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::IsEllipticCurve,
+    },
+    errors::ByteConversionError,
+    field::element::FieldElement,
+    traits::ByteConversion,
+    unsigned_integer::element::U384,
+};
+
+type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+type FpElement = FieldElement<BLS12381PrimeField>;
+type Fp2Element = FieldElement<LevelOneField>;
+
+const COMPRESSION_FLAG: u8 = 0b1000_0000;
+const INFINITY_FLAG: u8 = 0b0100_0000;
+const SORT_FLAG: u8 = 0b0010_0000;
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG;
+
+/// Size in bytes of an encoded element of the base field.
+const FP_SIZE: usize = 48;
+
+/// Returns the compressed encoding of `point`, of 48 bytes.
+pub fn compress_g1_point(point: &G1Point) -> [u8; 48] {
+    let mut bytes = [0; 48];
+    if point.is_neutral_element() {
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+    } else {
+        let [x, y, _] = point.to_affine().coordinates().clone();
+        bytes.copy_from_slice(&fp_to_bytes(&x));
+        bytes[0] |= COMPRESSION_FLAG;
+        if is_lexicographically_largest_fp(&y) {
+            bytes[0] |= SORT_FLAG;
+        }
+    }
+    bytes
+}
+
+/// Returns the G1 point encoded by the 48 bytes in `bytes`.
+pub fn decompress_g1_point(bytes: &[u8]) -> Result<G1Point, ByteConversionError> {
+    let (flags, bytes) = split_flags(bytes, FP_SIZE)?;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(ByteConversionError::InvalidFlags);
+    }
+    if flags & INFINITY_FLAG != 0 {
+        return decode_infinity(flags, &bytes);
+    }
+    let x = fp_from_bytes(&bytes)?;
+    let (y, negated_y) = (x.pow(3_u16) + BLS12381Curve::b())
+        .sqrt()
+        .ok_or(ByteConversionError::PointNotInCurve)?;
+    let y = if is_lexicographically_largest_fp(&y) == (flags & SORT_FLAG != 0) {
+        y
+    } else {
+        negated_y
+    };
+    check_subgroup::<BLS12381Curve>(G1Point::new([x, y, FieldElement::one()]))
+}
+
+/// Returns the compressed encoding of `point`, of 96 bytes.
+pub fn compress_g2_point(point: &G2Point) -> [u8; 96] {
+    let mut bytes = [0; 96];
+    if point.is_neutral_element() {
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+    } else {
+        let [x, y, _] = point.to_affine().coordinates().clone();
+        bytes.copy_from_slice(&fp2_to_bytes(&x));
+        bytes[0] |= COMPRESSION_FLAG;
+        if is_lexicographically_largest_fp2(&y) {
+            bytes[0] |= SORT_FLAG;
+        }
+    }
+    bytes
+}
+
+/// Returns the G2 point encoded by the 96 bytes in `bytes`.
+pub fn decompress_g2_point(bytes: &[u8]) -> Result<G2Point, ByteConversionError> {
+    let (flags, bytes) = split_flags(bytes, 2 * FP_SIZE)?;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(ByteConversionError::InvalidFlags);
+    }
+    if flags & INFINITY_FLAG != 0 {
+        return decode_infinity(flags, &bytes);
+    }
+    let x = fp2_from_bytes(&bytes)?;
+    let (y, negated_y) = (x.pow(3_u16) + BLS12381TwistCurve::b())
+        .sqrt()
+        .ok_or(ByteConversionError::PointNotInCurve)?;
+    let y = if is_lexicographically_largest_fp2(&y) == (flags & SORT_FLAG != 0) {
+        y
+    } else {
+        negated_y
+    };
+    check_subgroup::<BLS12381TwistCurve>(G2Point::new([x, y, FieldElement::one()]))
+}
+
+/// Uncompressed encoding of G1 points, of 96 bytes. The little-endian
+/// encoding is the big-endian one in reverse order.
+impl ByteConversion for G1Point {
+    fn to_bytes_be(&self) -> Vec<u8> {
+        if self.is_neutral_element() {
+            let mut bytes = vec![0; 2 * FP_SIZE];
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        let [x, y, _] = self.to_affine().coordinates().clone();
+        [fp_to_bytes(&x), fp_to_bytes(&y)].concat()
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_be();
+        bytes.reverse();
+        bytes
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let (flags, bytes) = split_flags(bytes, 2 * FP_SIZE)?;
+        if flags & COMPRESSION_FLAG != 0 {
+            return Err(ByteConversionError::InvalidFlags);
+        }
+        if flags & INFINITY_FLAG != 0 {
+            return decode_infinity(flags, &bytes);
+        }
+        if flags & SORT_FLAG != 0 {
+            return Err(ByteConversionError::InvalidFlags);
+        }
+        let x = fp_from_bytes(&bytes[..FP_SIZE])?;
+        let y = fp_from_bytes(&bytes[FP_SIZE..])?;
+        let point = BLS12381Curve::create_point_from_affine(x, y)
+            .map_err(|_| ByteConversionError::PointNotInCurve)?;
+        check_subgroup::<BLS12381Curve>(point)
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_bytes_be(&bytes)
+    }
+}
+
+/// Uncompressed encoding of G2 points, of 192 bytes. The little-endian
+/// encoding is the big-endian one in reverse order.
+impl ByteConversion for G2Point {
+    fn to_bytes_be(&self) -> Vec<u8> {
+        if self.is_neutral_element() {
+            let mut bytes = vec![0; 4 * FP_SIZE];
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        let [x, y, _] = self.to_affine().coordinates().clone();
+        [fp2_to_bytes(&x), fp2_to_bytes(&y)].concat()
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_be();
+        bytes.reverse();
+        bytes
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let (flags, bytes) = split_flags(bytes, 4 * FP_SIZE)?;
+        if flags & COMPRESSION_FLAG != 0 {
+            return Err(ByteConversionError::InvalidFlags);
+        }
+        if flags & INFINITY_FLAG != 0 {
+            return decode_infinity(flags, &bytes);
+        }
+        if flags & SORT_FLAG != 0 {
+            return Err(ByteConversionError::InvalidFlags);
+        }
+        let x = fp2_from_bytes(&bytes[..2 * FP_SIZE])?;
+        let y = fp2_from_bytes(&bytes[2 * FP_SIZE..])?;
+        let point = BLS12381TwistCurve::create_point_from_affine(x, y)
+            .map_err(|_| ByteConversionError::PointNotInCurve)?;
+        check_subgroup::<BLS12381TwistCurve>(point)
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_bytes_be(&bytes)
+    }
+}
+
+/// Checks that `bytes` has length `size` and returns its flag bits
+/// together with a copy of `bytes` where they are cleared.
+fn split_flags(bytes: &[u8], size: usize) -> Result<(u8, Vec<u8>), ByteConversionError> {
+    if bytes.len() != size {
+        return Err(ByteConversionError::InvalidLength);
+    }
+    let mut bytes = bytes.to_vec();
+    let flags = bytes[0] & FLAGS_MASK;
+    bytes[0] &= !FLAGS_MASK;
+    Ok((flags, bytes))
+}
+
+/// Returns the point at infinity if the encoding is canonical: the sort flag
+/// is not set and every other bit is zero.
+fn decode_infinity<T: IsGroup>(flags: u8, bytes: &[u8]) -> Result<T, ByteConversionError> {
+    if flags & SORT_FLAG != 0 || bytes.iter().any(|byte| *byte != 0) {
+        return Err(ByteConversionError::InvalidFlags);
+    }
+    Ok(T::neutral_element())
+}
+
+fn check_subgroup<E: IsEllipticCurve>(
+    point: E::PointRepresentation,
+) -> Result<E::PointRepresentation, ByteConversionError> {
+    if E::is_in_subgroup(&point) {
+        Ok(point)
+    } else {
+        Err(ByteConversionError::PointNotInSubgroup)
+    }
+}
+
+fn fp_to_bytes(a: &FpElement) -> Vec<u8> {
+    a.representative().to_bytes_be()
+}
+
+fn fp_from_bytes(bytes: &[u8]) -> Result<FpElement, ByteConversionError> {
+    let value = U384::from_bytes_be(bytes)?;
+    if value >= BLS12381_PRIME_FIELD_ORDER {
+        return Err(ByteConversionError::ValueNotReduced);
+    }
+    Ok(FieldElement::new(value))
+}
+
+fn fp2_to_bytes(a: &Fp2Element) -> Vec<u8> {
+    let [a0, a1] = a.value();
+    [fp_to_bytes(a1), fp_to_bytes(a0)].concat()
+}
+
+fn fp2_from_bytes(bytes: &[u8]) -> Result<Fp2Element, ByteConversionError> {
+    let a1 = fp_from_bytes(&bytes[..FP_SIZE])?;
+    let a0 = fp_from_bytes(&bytes[FP_SIZE..])?;
+    Ok(FieldElement::new([a0, a1]))
+}
+
+/// Returns `true` if `a > -a` as integers in the range `[0, p)`.
+fn is_lexicographically_largest_fp(a: &FpElement) -> bool {
+    a.representative() > (-a).representative()
+}
+
+/// Compares `a1` first and `a0` if `a1` is zero, where `a = a0 + a1 * u`.
+fn is_lexicographically_largest_fp2(a: &Fp2Element) -> bool {
+    let [a0, a1] = a.value();
+    if *a1 == FieldElement::zero() {
+        is_lexicographically_largest_fp(a0)
+    } else {
+        is_lexicographically_largest_fp(a1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const G1_GENERATOR_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G2_GENERATOR_COMPRESSED: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+    #[test]
+    fn compress_g1_generator_matches_the_zcash_encoding() {
+        let bytes = compress_g1_point(&BLS12381Curve::generator());
+        assert_eq!(bytes.to_vec(), hex_to_bytes(G1_GENERATOR_COMPRESSED));
+    }
+
+    #[test]
+    fn compress_g2_generator_matches_the_zcash_encoding() {
+        let bytes = compress_g2_point(&BLS12381TwistCurve::generator());
+        assert_eq!(bytes.to_vec(), hex_to_bytes(G2_GENERATOR_COMPRESSED));
+    }
+
+    #[test]
+    fn decompress_inverts_compress() {
+        let g1 = BLS12381Curve::generator();
+        let g2 = BLS12381TwistCurve::generator();
+        for k in 1..5_u128 {
+            let p = g1.operate_with_self(k);
+            let q = g2.operate_with_self(k);
+            assert_eq!(decompress_g1_point(&compress_g1_point(&p)).unwrap(), p);
+            assert_eq!(
+                decompress_g1_point(&compress_g1_point(&p.neg())).unwrap(),
+                p.neg()
+            );
+            assert_eq!(decompress_g2_point(&compress_g2_point(&q)).unwrap(), q);
+            assert_eq!(
+                decompress_g2_point(&compress_g2_point(&q.neg())).unwrap(),
+                q.neg()
+            );
+        }
+    }
+
+    #[test]
+    fn uncompressed_serialization_round_trips() {
+        let p = BLS12381Curve::generator().operate_with_self(7_u128);
+        let q = BLS12381TwistCurve::generator().operate_with_self(7_u128);
+        assert_eq!(p.to_bytes_be().len(), 96);
+        assert_eq!(q.to_bytes_be().len(), 192);
+        assert_eq!(G1Point::from_bytes_be(&p.to_bytes_be()).unwrap(), p);
+        assert_eq!(G1Point::from_bytes_le(&p.to_bytes_le()).unwrap(), p);
+        assert_eq!(G2Point::from_bytes_be(&q.to_bytes_be()).unwrap(), q);
+        assert_eq!(G2Point::from_bytes_le(&q.to_bytes_le()).unwrap(), q);
+    }
+
+    #[test]
+    fn uncompressed_g1_generator_starts_with_x() {
+        let bytes = BLS12381Curve::generator().to_bytes_be();
+        let mut expected = hex_to_bytes(G1_GENERATOR_COMPRESSED);
+        expected[0] &= !FLAGS_MASK;
+        assert_eq!(bytes[..48], expected);
+    }
+
+    #[test]
+    fn point_at_infinity_round_trips() {
+        let compressed = compress_g1_point(&G1Point::neutral_element());
+        assert_eq!(compressed[0], 0xc0);
+        assert!(compressed[1..].iter().all(|byte| *byte == 0));
+        assert!(decompress_g1_point(&compressed)
+            .unwrap()
+            .is_neutral_element());
+        assert!(
+            decompress_g2_point(&compress_g2_point(&G2Point::neutral_element()))
+                .unwrap()
+                .is_neutral_element()
+        );
+        let uncompressed = G2Point::neutral_element().to_bytes_be();
+        assert_eq!(uncompressed[0], 0x40);
+        assert!(G2Point::from_bytes_be(&uncompressed)
+            .unwrap()
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn decoding_with_wrong_length_fails() {
+        assert_eq!(
+            decompress_g1_point(&[0xc0; 47]),
+            Err(ByteConversionError::InvalidLength)
+        );
+        assert_eq!(
+            decompress_g2_point(&[0xc0; 97]),
+            Err(ByteConversionError::InvalidLength)
+        );
+        assert_eq!(
+            G1Point::from_bytes_le(&[0x40; 95]),
+            Err(ByteConversionError::InvalidLength)
+        );
+        assert_eq!(
+            G2Point::from_bytes_be(&[0x40; 191]),
+            Err(ByteConversionError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn decoding_with_invalid_flags_fails() {
+        let p = BLS12381Curve::generator();
+        // Uncompressed encoding passed as compressed.
+        let mut bytes = compress_g1_point(&p);
+        bytes[0] &= !COMPRESSION_FLAG;
+        assert_eq!(
+            decompress_g1_point(&bytes),
+            Err(ByteConversionError::InvalidFlags)
+        );
+        // Compressed flag in the uncompressed encoding.
+        let mut bytes = p.to_bytes_be();
+        bytes[0] |= COMPRESSION_FLAG;
+        assert_eq!(
+            G1Point::from_bytes_be(&bytes),
+            Err(ByteConversionError::InvalidFlags)
+        );
+        // Point at infinity with nonzero bits.
+        let mut bytes = compress_g1_point(&G1Point::neutral_element());
+        bytes[47] = 1;
+        assert_eq!(
+            decompress_g1_point(&bytes),
+            Err(ByteConversionError::InvalidFlags)
+        );
+    }
+
+    #[test]
+    fn decoding_a_non_reduced_coordinate_fails() {
+        let mut bytes = BLS12381_PRIME_FIELD_ORDER.to_bytes_be();
+        bytes[0] |= COMPRESSION_FLAG;
+        assert_eq!(
+            decompress_g1_point(&bytes),
+            Err(ByteConversionError::ValueNotReduced)
+        );
+    }
+
+    #[test]
+    fn decoding_a_point_not_in_the_curve_fails() {
+        // x = 1 gives y^2 = 5, which is not a square.
+        let mut bytes = FpElement::one().representative().to_bytes_be();
+        bytes[0] |= COMPRESSION_FLAG;
+        assert_eq!(
+            decompress_g1_point(&bytes),
+            Err(ByteConversionError::PointNotInCurve)
+        );
+        let mut bytes = BLS12381Curve::generator().to_bytes_be();
+        bytes[95] ^= 1;
+        assert_eq!(
+            G1Point::from_bytes_be(&bytes),
+            Err(ByteConversionError::PointNotInCurve)
+        );
+    }
+
+    #[test]
+    fn decoding_a_point_not_in_the_subgroup_fails() {
+        // (x, y) belongs to the curve but not to the subgroup of order r.
+        let x = FpElement::from(4);
+        let (y, _) = (x.pow(3_u16) + BLS12381Curve::b()).sqrt().unwrap();
+        let point = G1Point::new([x, y, FieldElement::one()]);
+        assert_eq!(
+            decompress_g1_point(&compress_g1_point(&point)),
+            Err(ByteConversionError::PointNotInSubgroup)
+        );
+        assert_eq!(
+            G1Point::from_bytes_be(&point.to_bytes_be()),
+            Err(ByteConversionError::PointNotInSubgroup)
+        );
+    }
+}
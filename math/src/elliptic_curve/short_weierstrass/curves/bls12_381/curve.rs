@@ -1,10 +1,21 @@
+use crate::cyclic_group::IsGroupWithNegation;
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
 use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::U256;
 use crate::{
     elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
 };
 
-use super::field_extension::BLS12381PrimeField;
+use super::{default_types::FrField, field_extension::BLS12381PrimeField};
+
+/// Absolute value of the parameter `x = -0xd201000000010000` that defines the
+/// BLS12-381 family of curves. The Miller loop and the final exponentiation
+/// of the pairing iterate over its bits.
+pub const MILLER_LOOP_CONSTANT: u64 = 0xd201000000010000;
+
+/// Order `r` of the subgroups G1 and G2.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
 
 /// The description of the curve.
 #[derive(Clone, Debug)]
@@ -12,6 +23,8 @@ pub struct BLS12381Curve;
 
 impl IsEllipticCurve for BLS12381Curve {
     type BaseField = BLS12381PrimeField;
+    type ScalarField = FrField;
+    type Cofactor = u128;
     type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
 
     fn generator() -> Self::PointRepresentation {
@@ -21,6 +34,35 @@ impl IsEllipticCurve for BLS12381Curve {
             FieldElement::one()
         ])
     }
+
+    /// `(x - 1)^2 / 3`, where `x` is the parameter of the curve.
+    fn cofactor() -> u128 {
+        0x396c8c005555e1568c00aaab0000aaab
+    }
+
+    /// Checks that `phi(p) == [-x^2]p`, where `x` is the parameter of the curve.
+    /// Taken from "Faster subgroup checks for BLS12-381" (https://eprint.iacr.org/2021/1130).
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        let x_square = (MILLER_LOOP_CONSTANT as u128).pow(2);
+        p.phi() == p.operate_with_self_wnaf(x_square).neg()
+    }
+
+    /// Multiplies `p` by the effective cofactor `h_eff = 1 - x` of RFC 9380, section 8.8.1.
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.operate_with_self_wnaf(MILLER_LOOP_CONSTANT + 1)
+    }
+}
+
+impl ShortWeierstrassProjectivePoint<BLS12381Curve> {
+    /// The endomorphism `phi(x, y) = (beta * x, y)`, where `beta` is a
+    /// primitive cube root of unity in the base field.
+    pub fn phi(&self) -> Self {
+        let beta = FieldElement::<BLS12381PrimeField>::new_base(
+            "5f19672fdf76ce51ba69c6076a0f77eaddb3a93be6f89688de17d813620a00022e01fffffffefffe",
+        );
+        let [x, y, z] = self.coordinates();
+        Self::new([x * beta, y.clone(), z.clone()])
+    }
 }
 
 impl IsShortWeierstrass for BLS12381Curve {
@@ -36,11 +78,14 @@ impl IsShortWeierstrass for BLS12381Curve {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic_curve::short_weierstrass::test_points;
     use crate::{
         cyclic_group::IsGroup, elliptic_curve::traits::EllipticCurveError,
-        field::element::FieldElement,
+        field::element::FieldElement, traits::RandomSample,
     };
+    use rand::{rngs::StdRng, SeedableRng};
 
+    use super::super::default_types::FrElement;
     use super::BLS12381Curve;
 
     #[allow(clippy::upper_case_acronyms)]
@@ -94,4 +139,116 @@ mod tests {
         let g = BLS12381Curve::generator();
         assert_eq!(g.operate_with(&g).operate_with(&g), g.operate_with_self(3));
     }
+
+    #[test]
+    fn random_points_are_in_the_subgroup_generated_by_the_generator() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..4 {
+            let p = ShortWeierstrassProjectivePoint::<BLS12381Curve>::random(&mut rng);
+            let p_affine = p.to_affine();
+            assert_eq!(
+                BLS12381Curve::create_point_from_affine(p_affine.x().clone(), p_affine.y().clone()),
+                Ok(p.clone())
+            );
+            assert!(p
+                .operate_with_self_wnaf(SUBGROUP_ORDER)
+                .is_neutral_element());
+        }
+    }
+
+    #[test]
+    fn operate_with_self_wnaf_matches_operate_with_self() {
+        let g = BLS12381Curve::generator();
+        for k in [0_u128, 1, 2, 7, 0xd201000000010000, u128::MAX] {
+            assert_eq!(g.operate_with_self_wnaf(k), g.operate_with_self(k));
+        }
+        let (order_plus_one, _) = U256::add(&SUBGROUP_ORDER, &U256::from_u64(1));
+        assert_eq!(g.operate_with_self_wnaf(order_plus_one), g);
+    }
+
+    fn point_not_in_the_subgroup() -> ShortWeierstrassProjectivePoint<BLS12381Curve> {
+        test_points::point_not_in_the_subgroup::<BLS12381Curve>(FEE::from, |x| x.sqrt())
+    }
+
+    #[test]
+    fn is_in_subgroup_matches_the_multiplication_by_the_order() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let p = point_not_in_the_subgroup();
+        let q = ShortWeierstrassProjectivePoint::<BLS12381Curve>::random(&mut rng);
+        for point in [BLS12381Curve::generator(), p.clone(), q, p.operate_with(&p)] {
+            assert_eq!(
+                BLS12381Curve::is_in_subgroup(&point),
+                point
+                    .operate_with_self_wnaf(SUBGROUP_ORDER)
+                    .is_neutral_element()
+            );
+        }
+        assert!(!BLS12381Curve::is_in_subgroup(&p));
+        assert!(BLS12381Curve::is_in_subgroup(
+            &ShortWeierstrassProjectivePoint::neutral_element()
+        ));
+    }
+
+    #[test]
+    fn clear_cofactor_maps_points_to_the_subgroup() {
+        let p = point_not_in_the_subgroup();
+        let q = BLS12381Curve::clear_cofactor(&p);
+        assert!(BLS12381Curve::is_in_subgroup(&q));
+        assert_eq!(q, p.operate_with_self(0xd201000000010001));
+    }
+
+    #[test]
+    fn create_point_in_subgroup_from_affine_rejects_points_outside_the_subgroup() {
+        let [x, y, _] = point_not_in_the_subgroup().coordinates().clone();
+        assert_eq!(
+            BLS12381Curve::create_point_in_subgroup_from_affine(x, y),
+            Err(EllipticCurveError::PointNotInSubgroup)
+        );
+        let g = BLS12381Curve::generator();
+        assert_eq!(
+            BLS12381Curve::create_point_in_subgroup_from_affine(g.x().clone(), g.y().clone()),
+            Ok(g)
+        );
+    }
+
+    #[test]
+    fn multiplication_by_scalar_field_elements_works() {
+        let g = BLS12381Curve::generator();
+        let a = FrElement::from(0xd201000000010000);
+        let b = -FrElement::from(7);
+        assert_eq!(&g * FrElement::from(5), g.operate_with_self(5));
+        assert_eq!(&g * (&a * &b), (&g * &a) * &b);
+        assert_eq!(g.clone() * -FrElement::one(), g.neg());
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn operate_with_constant_time_matches_operate_with() {
+        use crate::constant_time::IsConstantTimeGroup;
+        let g = BLS12381Curve::generator();
+        let g2 = g.operate_with_self(2);
+        let infinity = ShortWeierstrassProjectivePoint::neutral_element();
+        assert_eq!(g.operate_with_constant_time(&g2), g.operate_with(&g2));
+        assert_eq!(g.operate_with_constant_time(&g), g2);
+        assert_eq!(g.operate_with_constant_time(&g.neg()), infinity);
+        assert_eq!(g.operate_with_constant_time(&infinity), g);
+        assert_eq!(infinity.operate_with_constant_time(&g), g);
+        assert_eq!(infinity.operate_with_constant_time(&infinity), infinity);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn operate_with_self_constant_time_matches_operate_with_self() {
+        use crate::{constant_time::IsConstantTimeGroup, unsigned_integer::element::U256};
+        let g = BLS12381Curve::generator();
+        for k in [0_u64, 1, 2, 5, 0xd201000000010000] {
+            assert_eq!(
+                g.operate_with_self_constant_time(&U256::from_u64(k)),
+                g.operate_with_self(k as u128)
+            );
+        }
+        assert!(g
+            .operate_with_self_constant_time(&SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
 }
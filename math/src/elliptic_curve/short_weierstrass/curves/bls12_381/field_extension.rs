@@ -4,7 +4,7 @@ use crate::field::{
         cubic::{CubicExtensionField, HasCubicNonResidue},
         quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
     },
-    fields::u384_prime_field::{IsMontgomeryConfiguration, MontgomeryBackendPrimeField},
+    fields::montgomery_backed_prime_fields::{IsMontgomeryConfiguration, U384PrimeField},
 };
 use crate::unsigned_integer::element::U384;
 
@@ -13,13 +13,11 @@ pub const BLS12381_PRIME_FIELD_ORDER: U384 = U384::from("1a0111ea397fe69a4b1ba7b
 // FPBLS12381
 #[derive(Clone, Debug)]
 pub struct BLS12381FieldConfig;
-impl IsMontgomeryConfiguration for BLS12381FieldConfig {
+impl IsMontgomeryConfiguration<6> for BLS12381FieldConfig {
     const MODULUS: U384 = BLS12381_PRIME_FIELD_ORDER;
-    const MP: u64 = 9940570264628428797;
-    const R2: U384 = U384::from("11988fe592cae3aa9a793e85b519952d67eb88a9939d83c08de5476c4c95b6d50a76e6a609d104f1f4df1f341c341746");
 }
 
-pub type BLS12381PrimeField = MontgomeryBackendPrimeField<BLS12381FieldConfig>;
+pub type BLS12381PrimeField = U384PrimeField<BLS12381FieldConfig>;
 
 #[derive(Debug, Clone)]
 pub struct LevelOneResidue;
@@ -31,7 +29,7 @@ impl HasQuadraticNonResidue for LevelOneResidue {
     }
 }
 
-type LevelOneField = QuadraticExtensionField<LevelOneResidue>;
+pub type LevelOneField = QuadraticExtensionField<LevelOneResidue>;
 
 #[derive(Debug, Clone)]
 pub struct LevelTwoResidue;
@@ -43,7 +41,7 @@ impl HasCubicNonResidue for LevelTwoResidue {
     }
 }
 
-type LevelTwoField = CubicExtensionField<LevelTwoResidue>;
+pub type LevelTwoField = CubicExtensionField<LevelTwoResidue>;
 
 #[derive(Debug, Clone)]
 pub struct LevelThreeResidue;
@@ -79,3 +77,48 @@ impl FieldElement<Order12ExtensionField> {
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::RandomSample;
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    type FpE = FieldElement<BLS12381PrimeField>;
+    type Fp12E = FieldElement<Order12ExtensionField>;
+
+    prop_compose! {
+        fn field_element()(seed in any::<u64>()) -> FpE {
+            FpE::random(&mut StdRng::seed_from_u64(seed))
+        }
+    }
+    prop_compose! {
+        fn order_12_element()(seed in any::<u64>()) -> Fp12E {
+            Fp12E::random(&mut StdRng::seed_from_u64(seed))
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn mul_by_inverse_is_one(a in field_element()) {
+            prop_assume!(a != FpE::zero());
+            prop_assert_eq!(&a * a.inv(), FpE::one());
+        }
+
+        #[test]
+        fn mul_distributes_over_add(a in field_element(), b in field_element(), c in field_element()) {
+            prop_assert_eq!(&a * (&b + &c), &a * &b + &a * &c);
+        }
+
+        #[test]
+        fn sub_is_add_of_negation(a in field_element(), b in field_element()) {
+            prop_assert_eq!(&a - &b, a + -b);
+        }
+
+        #[test]
+        fn order_12_mul_by_inverse_is_one(a in order_12_element()) {
+            prop_assert_eq!(&a * a.inv(), Fp12E::one());
+        }
+    }
+}
@@ -1,2 +1,6 @@
+pub mod compression;
 pub mod curve;
+pub mod default_types;
 pub mod field_extension;
+pub mod pairing;
+pub mod twist;
@@ -0,0 +1,328 @@
+use super::{
+    curve::{BLS12381Curve, MILLER_LOOP_CONSTANT},
+    field_extension::{BLS12381PrimeField, LevelOneField, LevelTwoField, Order12ExtensionField},
+    twist::BLS12381TwistCurve,
+};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::point::ShortWeierstrassProjectivePoint, traits::IsPairing,
+    },
+    field::{element::FieldElement, errors::FieldError},
+};
+
+type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+
+/// `(x - 1)^2`, where `x` is the BLS12-381 parameter. Used in the hard part
+/// of the final exponentiation.
+const HARD_PART_CONSTANT: u128 = 0xac45a4010001a403a402000100020001;
+
+/// The optimal ate pairing of BLS12-381, with G1 in `BLS12381Curve` and G2
+/// in its sextic twist `BLS12381TwistCurve`.
+#[derive(Clone, Debug)]
+pub struct BLS12381AtePairing;
+
+impl IsPairing for BLS12381AtePairing {
+    type G1Point = G1Point;
+    type G2Point = G2Point;
+    type OutputField = Order12ExtensionField;
+
+    /// Computes the product of the ate pairings of `pairs` with a single
+    /// final exponentiation.
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> Result<FieldElement<Self::OutputField>, FieldError> {
+        let mut result = FieldElement::one();
+        for (p, q) in pairs {
+            if !p.is_neutral_element() && !q.is_neutral_element() {
+                let p = p.try_to_affine()?;
+                let q = q.try_to_affine()?;
+                result = result * miller(&q, &p)?;
+            }
+        }
+        final_exponentiation(&result)
+    }
+}
+
+/// Evaluates at `p` the line through the point `(xt, yt)` of the twist with slope `lambda`.
+/// The line is computed on the untwisted points and then multiplied by `w^3`,
+/// which gives the sparse element `(lambda * xt - yt) - lambda * xp * w^2 + yp * w^3`.
+/// The factor `w^3` lies in a proper subfield and is cleared by the final exponentiation.
+fn line(
+    xt: &FieldElement<LevelOneField>,
+    yt: &FieldElement<LevelOneField>,
+    lambda: &FieldElement<LevelOneField>,
+    p: &G1Point,
+) -> FieldElement<Order12ExtensionField> {
+    let [xp, yp, _] = p.coordinates();
+    let xp = FieldElement::<LevelOneField>::new([xp.clone(), FieldElement::zero()]);
+    let yp = FieldElement::<LevelOneField>::new([yp.clone(), FieldElement::zero()]);
+    FieldElement::new([
+        FieldElement::new([lambda * xt - yt, -lambda * xp, FieldElement::zero()]),
+        FieldElement::new([FieldElement::zero(), yp, FieldElement::zero()]),
+    ])
+}
+
+/// Computes Miller's loop of the ate pairing over the bits of `MILLER_LOOP_CONSTANT`.
+/// Both `q` and `p` must be in affine coordinates and different from the point at infinity.
+/// Vertical lines are omitted since they are cleared by the final exponentiation.
+/// See "Pairings for beginners" from Craig Costello, Algorithm 5.1, page 79.
+fn miller(q: &G2Point, p: &G1Point) -> Result<FieldElement<Order12ExtensionField>, FieldError> {
+    let [xq, yq, _] = q.coordinates();
+    let (mut xt, mut yt) = (xq.clone(), yq.clone());
+    let mut f = FieldElement::one();
+    let two = FieldElement::from(2);
+    let three = FieldElement::from(3);
+
+    let bits = 64 - MILLER_LOOP_CONSTANT.leading_zeros();
+    for i in (0..bits - 1).rev() {
+        let lambda = (&three * xt.pow(2_u16)).try_div(&(&two * &yt))?;
+        f = f.pow(2_u16) * line(&xt, &yt, &lambda, p);
+        let x = lambda.pow(2_u16) - &two * &xt;
+        yt = lambda * (xt - &x) - yt;
+        xt = x;
+
+        if (MILLER_LOOP_CONSTANT >> i) & 1 == 1 {
+            let lambda = (yq - &yt).try_div(&(xq - &xt))?;
+            f = f * line(&xt, &yt, &lambda, p);
+            let x = lambda.pow(2_u16) - &xt - xq;
+            yt = lambda * (xt - &x) - yt;
+            xt = x;
+        }
+    }
+    // The parameter `x` of BLS12-381 is negative. The inverse of `f` and its conjugate
+    // agree after the final exponentiation.
+    Ok(conjugate(&f))
+}
+
+/// Raises `f` to the power `3 * (p^12 - 1) / r`.
+/// The easy part computes `f^((p^6 - 1) * (p^2 + 1))`. The hard part uses the decomposition
+/// `3 * (p^4 - p^2 + 1) / r = (x - 1)^2 * (x + p) * (x^2 + p^2 - 1) + 3`.
+/// Since `3` does not divide `r` this is still a pairing, and its values agree with
+/// other BLS12-381 implementations such as blst and zkcrypto's.
+fn final_exponentiation(
+    f: &FieldElement<Order12ExtensionField>,
+) -> Result<FieldElement<Order12ExtensionField>, FieldError> {
+    // Easy part
+    let f_easy_aux = conjugate(f) * f.try_inv()?;
+    let f_easy = frobenius_square(&f_easy_aux) * &f_easy_aux;
+
+    // Hard part
+    let a = f_easy.pow(HARD_PART_CONSTANT);
+    let b = cyclotomic_pow_x(&a) * frobenius(&a);
+    let c = cyclotomic_pow_x(&cyclotomic_pow_x(&b)) * frobenius_square(&b) * conjugate(&b);
+    Ok(c * f_easy.pow(3_u16))
+}
+
+/// Raises `f` to the power `x`, the parameter of the curve.
+/// `f` must belong to the cyclotomic subgroup, where the inverse is the conjugate.
+fn cyclotomic_pow_x(
+    f: &FieldElement<Order12ExtensionField>,
+) -> FieldElement<Order12ExtensionField> {
+    conjugate(&f.pow(MILLER_LOOP_CONSTANT))
+}
+
+/// Returns `a0 - a1 * w` for `f = a0 + a1 * w`. This is `f^(p^6)`.
+fn conjugate(f: &FieldElement<Order12ExtensionField>) -> FieldElement<Order12ExtensionField> {
+    let [a0, a1] = f.value();
+    FieldElement::new([a0.clone(), -a1])
+}
+
+/// `(1 + u)^((p - 1) / 6)`.
+fn gamma_12() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BLS12381PrimeField>::new_base("1904d3bf02bb0667c231beb4202c0d1f0fd603fd3cbd5f4f7b2443d784bab9c4f67ea53d63e7813d8d0775ed92235fb8"),
+        FieldElement::<BLS12381PrimeField>::new_base("fc3e2b36c4e03288e9e902231f9fb854a14787b6c7b36fec0c8ec971f63c5f282d5ac14d6c7ec22cf78a126ddc4af3"),
+    ])
+}
+
+/// `(1 + u)^((p - 1) / 3)`.
+fn gamma_6() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::zero(),
+        FieldElement::<BLS12381PrimeField>::new_base("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaac"),
+    ])
+}
+
+/// `(1 + u)^(2 * (p - 1) / 3)`.
+fn gamma_6_square() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BLS12381PrimeField>::new_base("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad"),
+        FieldElement::zero(),
+    ])
+}
+
+fn frobenius_level_one(a: &FieldElement<LevelOneField>) -> FieldElement<LevelOneField> {
+    let [a0, a1] = a.value();
+    FieldElement::new([a0.clone(), -a1])
+}
+
+fn frobenius_level_two(a: &FieldElement<LevelTwoField>) -> FieldElement<LevelTwoField> {
+    let [a0, a1, a2] = a.value();
+    FieldElement::new([
+        frobenius_level_one(a0),
+        frobenius_level_one(a1) * gamma_6(),
+        frobenius_level_one(a2) * gamma_6_square(),
+    ])
+}
+
+/// Returns `f^p`.
+fn frobenius(f: &FieldElement<Order12ExtensionField>) -> FieldElement<Order12ExtensionField> {
+    let [a0, a1] = f.value();
+    let gamma = FieldElement::new([gamma_12(), FieldElement::zero(), FieldElement::zero()]);
+    FieldElement::new([frobenius_level_two(a0), frobenius_level_two(a1) * gamma])
+}
+
+/// Returns `f^(p^2)`.
+fn frobenius_square(
+    f: &FieldElement<Order12ExtensionField>,
+) -> FieldElement<Order12ExtensionField> {
+    frobenius(&frobenius(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_381::curve::SUBGROUP_ORDER, traits::IsEllipticCurve,
+        },
+        unsigned_integer::element::UnsignedInteger,
+    };
+
+    #[test]
+    fn batch_ate_pairing_bilinearity() {
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        let a = 11_u128;
+        let b = 93_u128;
+        let result = BLS12381AtePairing::compute_batch(&[
+            (&p.operate_with_self(a), &q.operate_with_self(b)),
+            (&p.operate_with_self(a * b), &q.neg()),
+        ])
+        .unwrap();
+        assert_eq!(result, FieldElement::one());
+    }
+
+    #[test]
+    fn ate_pairing_bilinearity() {
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        let e = BLS12381AtePairing::compute(&p, &q).unwrap();
+        assert_eq!(
+            BLS12381AtePairing::compute(&p.operate_with_self(2), &q).unwrap(),
+            e.pow(2_u16)
+        );
+        assert_eq!(
+            BLS12381AtePairing::compute(&p, &q.operate_with_self(3)).unwrap(),
+            e.pow(3_u16)
+        );
+    }
+
+    #[test]
+    fn ate_pairing_is_non_degenerate() {
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        let e = BLS12381AtePairing::compute(&p, &q).unwrap();
+        assert_ne!(e, FieldElement::one());
+        assert_eq!(e.pow(SUBGROUP_ORDER), FieldElement::one());
+    }
+
+    #[test]
+    fn ate_pairing_of_the_generators_matches_reference_value() {
+        // Coefficients of `e(g1, g2)` as computed by the blst library, in the
+        // tower `Fp12 = Fp6[w] / (w^2 - v)`, `Fp6 = Fp2[v] / (v^3 - (1 + u))`.
+        let fp2 = |c0: &str, c1: &str| -> FieldElement<LevelOneField> {
+            FieldElement::new([
+                FieldElement::<BLS12381PrimeField>::new_base(c0),
+                FieldElement::<BLS12381PrimeField>::new_base(c1),
+            ])
+        };
+        let expected = FieldElement::<Order12ExtensionField>::new([
+            FieldElement::<LevelTwoField>::new([
+                fp2(
+                    "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+                    "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+                ),
+                fp2(
+                    "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+                    "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+                ),
+                fp2(
+                    "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+                    "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+                ),
+            ]),
+            FieldElement::<LevelTwoField>::new([
+                fp2(
+                    "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+                    "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+                ),
+                fp2(
+                    "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+                    "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+                ),
+                fp2(
+                    "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+                    "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+                ),
+            ]),
+        ]);
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        assert_eq!(BLS12381AtePairing::compute(&p, &q).unwrap(), expected);
+    }
+
+    #[test]
+    fn ate_pairing_with_the_point_at_infinity_is_one() {
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        assert_eq!(
+            BLS12381AtePairing::compute(&G1Point::neutral_element(), &q).unwrap(),
+            FieldElement::one()
+        );
+        assert_eq!(
+            BLS12381AtePairing::compute(&p, &G2Point::neutral_element()).unwrap(),
+            FieldElement::one()
+        );
+    }
+
+    #[test]
+    fn ate_pairing_with_a_malformed_point_is_an_error() {
+        // A point with `y = 0` outside the curve makes the doubling divide by zero.
+        let p = BLS12381Curve::generator();
+        let q = G2Point::new([
+            FieldElement::one(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        assert_eq!(
+            BLS12381AtePairing::compute(&p, &q),
+            Err(FieldError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn final_exponentiation_hard_part_matches_the_cube_of_the_naive_exponentiation() {
+        // (p^4 - p^2 + 1) / r
+        let hard_part_exponent = UnsignedInteger::<20>::from("f686b3d807d01c0bd38c3195c899ed3cde88eeb996ca394506632528d6a9a2f230063cf081517f68f7764c28b6f8ae5a72bce8d63cb9f827eca0ba621315b2076995003fc77a17988f8761bdc51dc2378b9039096d1b767f17fcbde783765915c97f36c6f18212ed0b283ed237db421d160aeb6a1e79983774940996754c8c71a2629b0dea236905ce937335d5b68fa9912aae208ccf1e516c3f438e3ba79");
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        let f = miller(&q, &p).unwrap();
+        let f_easy_aux = conjugate(&f) * f.inv();
+        let f_easy = frobenius_square(&f_easy_aux) * &f_easy_aux;
+        assert_eq!(
+            final_exponentiation(&f).unwrap(),
+            f_easy.pow(hard_part_exponent).pow(3_u16)
+        );
+    }
+
+    #[test]
+    fn frobenius_is_the_p_power() {
+        let [x, y] = BLS12381TwistCurve::generator().untwist();
+        let f = x * y;
+        let p = UnsignedInteger::<6>::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        assert_eq!(frobenius(&f), f.pow(p));
+    }
+}
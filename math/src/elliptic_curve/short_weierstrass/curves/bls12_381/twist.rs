@@ -0,0 +1,223 @@
+use super::curve::MILLER_LOOP_CONSTANT;
+use super::default_types::FrField;
+use super::field_extension::{BLS12381PrimeField, LevelOneField, Order12ExtensionField};
+use crate::cyclic_group::{IsGroup, IsGroupWithNegation};
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::UnsignedInteger;
+use crate::{
+    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+};
+
+/// The sextic twist `y^2 = x^3 + 4 * (1 + u)` of BLS12-381 over the quadratic
+/// extension `LevelOneField`. Its subgroup of order `r` is the G2 of the pairing.
+#[derive(Clone, Debug)]
+pub struct BLS12381TwistCurve;
+
+impl IsEllipticCurve for BLS12381TwistCurve {
+    type BaseField = LevelOneField;
+    type ScalarField = FrField;
+    type Cofactor = UnsignedInteger<8>;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::new([
+                FieldElement::<BLS12381PrimeField>::new_base("24aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                FieldElement::<BLS12381PrimeField>::new_base("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+            ]),
+            FieldElement::new([
+                FieldElement::<BLS12381PrimeField>::new_base("ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                FieldElement::<BLS12381PrimeField>::new_base("606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ]),
+            FieldElement::one(),
+        ])
+    }
+
+    /// `(x^8 - 4x^7 + 5x^6 - 4x^4 + 6x^3 - 4x^2 - 4x + 13) / 9`, where `x` is
+    /// the parameter of the curve.
+    fn cofactor() -> UnsignedInteger<8> {
+        UnsignedInteger::from("5d543a95414e7f1091d50792876a202cd91de4547085abaa68a205b2e5a7ddfa628f1cb4d9e82ef21537e293a6691ae1616ec6e786f0c70cf1c38e31c7238e5")
+    }
+
+    /// Checks that `psi(p) == [x]p`, where `x` is the parameter of the curve.
+    /// Taken from "Faster subgroup checks for BLS12-381" (https://eprint.iacr.org/2021/1130).
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.psi() == p.operate_with_self_wnaf(MILLER_LOOP_CONSTANT).neg()
+    }
+
+    /// Multiplies `p` by the effective cofactor `h_eff` of RFC 9380, section 8.8.2,
+    /// with the method of Budroni and Pintore based on the endomorphism `psi`.
+    /// The result is `[x^2 - x - 1]p + [x - 1]psi(p) + psi^2(2p)`.
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        // `x` is negative, so `[x]q` is `-[|x|]q`.
+        let times_x =
+            |q: &Self::PointRepresentation| q.operate_with_self_wnaf(MILLER_LOOP_CONSTANT).neg();
+        let t1 = times_x(p);
+        let t2 = p.psi();
+        let t3 = p.operate_with(p).psi().psi().operate_with(&t2.neg());
+        let t2 = times_x(&t1.operate_with(&t2));
+        t3.operate_with(&t2)
+            .operate_with(&t1.neg())
+            .operate_with(&p.neg())
+    }
+}
+
+impl IsShortWeierstrass for BLS12381TwistCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(0)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::new([FieldElement::from(4), FieldElement::from(4)])
+    }
+}
+
+impl ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+    /// Maps a point of the twist to the curve `y^2 = x^3 + 4` over
+    /// `Order12ExtensionField` and returns its affine coordinates.
+    /// The map is `(x, y) -> (x / w^2, y / w^3)`, where `w` is the generator
+    /// of `Order12ExtensionField` over `LevelTwoField` and `w^6 = 1 + u`.
+    /// Panics if `self` is the point at infinity.
+    pub fn untwist(&self) -> [FieldElement<Order12ExtensionField>; 2] {
+        let p = self.to_affine();
+        let w =
+            FieldElement::<Order12ExtensionField>::new([FieldElement::zero(), FieldElement::one()]);
+        let w_inv = w.inv();
+        let x = FieldElement::<Order12ExtensionField>::new([
+            FieldElement::new([p.x().clone(), FieldElement::zero(), FieldElement::zero()]),
+            FieldElement::zero(),
+        ]);
+        let y = FieldElement::<Order12ExtensionField>::new([
+            FieldElement::new([p.y().clone(), FieldElement::zero(), FieldElement::zero()]),
+            FieldElement::zero(),
+        ]);
+        [x * w_inv.pow(2_u16), y * w_inv.pow(3_u16)]
+    }
+
+    /// The endomorphism `psi = untwist^-1 o frobenius o untwist` of the twist,
+    /// which maps `(x, y)` to `(c1 * conj(x), c2 * conj(y))` with
+    /// `c1 = 1 / (1 + u)^((p - 1) / 3)` and `c2 = 1 / (1 + u)^((p - 1) / 2)`.
+    pub fn psi(&self) -> Self {
+        let c1 = FieldElement::<LevelOneField>::new([
+            FieldElement::zero(),
+            FieldElement::<BLS12381PrimeField>::new_base("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad"),
+        ]);
+        let c2 = FieldElement::<LevelOneField>::new([
+            FieldElement::<BLS12381PrimeField>::new_base("135203e60180a68ee2e9c448d77a2cd91c3dedd930b1cf60ef396489f61eb45e304466cf3e67fa0af1ee7b04121bdea2"),
+            FieldElement::<BLS12381PrimeField>::new_base("6af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09"),
+        ]);
+        let conjugate = |a: &FieldElement<LevelOneField>| {
+            let [a0, a1] = a.value();
+            FieldElement::new([a0.clone(), -a1])
+        };
+        let [x, y, z] = self.coordinates();
+        Self::new([conjugate(x) * c1, conjugate(y) * c2, conjugate(z)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::short_weierstrass::test_points;
+    use crate::elliptic_curve::{
+        short_weierstrass::curves::bls12_381::curve::SUBGROUP_ORDER, traits::EllipticCurveError,
+    };
+
+    #[test]
+    fn generator_belongs_to_the_twist() {
+        let g = BLS12381TwistCurve::generator();
+        assert_eq!(
+            BLS12381TwistCurve::defining_equation(g.coordinates()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn y_coordinate_of_the_generator_is_a_square_root_of_the_curve_equation() {
+        let g = BLS12381TwistCurve::generator();
+        let [x, y, _] = g.coordinates();
+        let (root, negated_root) = (x.pow(3_u16) + BLS12381TwistCurve::b()).sqrt().unwrap();
+        assert!(&root == y || &negated_root == y);
+    }
+
+    #[test]
+    fn create_invalid_points_returns_an_error() {
+        assert_eq!(
+            BLS12381TwistCurve::create_point_from_affine(
+                FieldElement::from(0),
+                FieldElement::from(1)
+            ),
+            Err(EllipticCurveError::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn operate_with_self_works_1() {
+        let g = BLS12381TwistCurve::generator();
+        assert_eq!(g.operate_with(&g).operate_with(&g), g.operate_with_self(3));
+    }
+
+    fn point_not_in_the_subgroup() -> ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+        test_points::point_not_in_the_subgroup::<BLS12381TwistCurve>(
+            |k| FieldElement::new([FieldElement::from(k), FieldElement::one()]),
+            |x| x.sqrt(),
+        )
+    }
+
+    #[test]
+    fn is_in_subgroup_matches_the_multiplication_by_the_order() {
+        let g = BLS12381TwistCurve::generator();
+        let p = point_not_in_the_subgroup();
+        for point in [g.clone(), g.operate_with_self(5), p.clone(), p.psi()] {
+            assert_eq!(
+                BLS12381TwistCurve::is_in_subgroup(&point),
+                point
+                    .operate_with_self_wnaf(SUBGROUP_ORDER)
+                    .is_neutral_element()
+            );
+        }
+        assert!(BLS12381TwistCurve::is_in_subgroup(&g));
+        assert!(!BLS12381TwistCurve::is_in_subgroup(&p));
+    }
+
+    #[test]
+    fn clear_cofactor_multiplies_by_the_effective_cofactor() {
+        let p = point_not_in_the_subgroup();
+        let h_eff = UnsignedInteger::<10>::from("bc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551");
+        let q = BLS12381TwistCurve::clear_cofactor(&p);
+        assert!(BLS12381TwistCurve::is_in_subgroup(&q));
+        assert_eq!(q, p.operate_with_self_wnaf(h_eff));
+    }
+
+    #[test]
+    fn untwisted_generator_belongs_to_the_curve_over_order_12_extension() {
+        let [x, y] = BLS12381TwistCurve::generator().untwist();
+        assert_eq!(y.pow(2_u16), x.pow(3_u16) + FieldElement::from(4));
+    }
+
+    #[test]
+    fn untwist_is_compatible_with_the_group_operation() {
+        let g = BLS12381TwistCurve::generator();
+        let [x1, y1] = g.untwist();
+        let [x2, _] = g.operate_with_self(2).untwist();
+        // Tangent line doubling formula on `y^2 = x^3 + 4`.
+        let lambda = FieldElement::from(3) * x1.pow(2_u16) / (FieldElement::from(2) * y1);
+        assert_eq!(x2, lambda.pow(2_u16) - FieldElement::from(2) * x1);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn operate_with_self_constant_time_matches_operate_with_self() {
+        use crate::{constant_time::IsConstantTimeGroup, unsigned_integer::element::U256};
+        let g = BLS12381TwistCurve::generator();
+        assert_eq!(
+            g.operate_with_constant_time(&g.neg()),
+            ShortWeierstrassProjectivePoint::neutral_element()
+        );
+        assert_eq!(
+            g.operate_with_self_constant_time(&U256::from_u64(11)),
+            g.operate_with_self(11)
+        );
+    }
+}
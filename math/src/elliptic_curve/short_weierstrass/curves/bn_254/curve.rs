@@ -0,0 +1,102 @@
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::U256;
+use crate::{
+    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+};
+
+use super::{default_types::FrField, field_extension::BN254PrimeField};
+
+/// The parameter `x` that defines the BN254 curve, with `p = 36x^4 + 36x^3 + 24x^2 + 6x + 1`.
+/// The final exponentiation of the pairing raises to powers of it.
+pub const X: u64 = 0x44e992b44a6909f1;
+
+/// `6x + 2`, where `x` is the parameter of the curve. The Miller loop of the
+/// optimal ate pairing iterates over its bits.
+pub const MILLER_LOOP_CONSTANT: u128 = 0x19d797039be763ba8;
+
+/// Order `r` of the subgroups G1 and G2.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+/// The curve `y^2 = x^3 + 3`, also known as alt_bn128, used by the Ethereum precompiles.
+#[derive(Clone, Debug)]
+pub struct BN254Curve;
+
+impl IsEllipticCurve for BN254Curve {
+    type BaseField = BN254PrimeField;
+    type ScalarField = FrField;
+    type Cofactor = u64;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::from(1),
+            FieldElement::from(2),
+            FieldElement::one(),
+        ])
+    }
+
+    /// The curve has prime order `r`.
+    fn cofactor() -> u64 {
+        1
+    }
+}
+
+impl IsShortWeierstrass for BN254Curve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(0)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::from(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::{IsGroup, IsGroupWithNegation},
+        elliptic_curve::traits::EllipticCurveError,
+    };
+
+    #[allow(clippy::upper_case_acronyms)]
+    type FEE = FieldElement<BN254PrimeField>;
+
+    #[test]
+    fn generator_times_two_matches_the_known_point() {
+        let g = BN254Curve::generator();
+        let expected = BN254Curve::create_point_from_affine(
+            FEE::new_base("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3"),
+            FEE::new_base("15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+        )
+        .unwrap();
+        assert_eq!(g.operate_with_self(2_u128), expected);
+    }
+
+    #[test]
+    fn create_invalid_points_returns_an_error() {
+        assert_eq!(
+            BN254Curve::create_point_from_affine(FEE::from(0), FEE::from(1)),
+            Err(EllipticCurveError::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn operate_with_self_works_1() {
+        let g = BN254Curve::generator();
+        assert_eq!(
+            g.operate_with(&g).operate_with(&g),
+            g.operate_with_self(3_u128)
+        );
+    }
+
+    #[test]
+    fn generator_has_the_order_of_the_subgroup() {
+        let g = BN254Curve::generator();
+        assert!(g
+            .operate_with_self_wnaf(SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
+}
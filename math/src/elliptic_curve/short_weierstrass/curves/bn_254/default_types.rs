@@ -0,0 +1,48 @@
+use crate::field::{
+    element::FieldElement,
+    fields::montgomery_backed_prime_fields::{IsMontgomeryConfiguration, U256PrimeField},
+};
+use crate::unsigned_integer::element::U256;
+
+/// Configuration of the scalar field of BN254, of order `r`.
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+impl IsMontgomeryConfiguration<4> for FrConfig {
+    const MODULUS: U256 =
+        U256::from("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+}
+
+/// The scalar field of BN254. Its order is the order of the subgroups G1 and G2.
+pub type FrField = U256PrimeField<FrConfig>;
+pub type FrElement = FieldElement<FrField>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroupWithNegation,
+        elliptic_curve::{
+            short_weierstrass::curves::bn_254::curve::BN254Curve, traits::IsEllipticCurve,
+        },
+    };
+
+    #[test]
+    fn minus_one_times_the_generator_is_its_inverse() {
+        let g = BN254Curve::generator();
+        let minus_one = -FrElement::one();
+        assert_eq!(
+            g.operate_with_self_wnaf(minus_one.representative()),
+            g.neg()
+        );
+    }
+
+    #[test]
+    fn field_operations_work_with_the_scalar_field_order() {
+        let a = FrElement::from(123456789);
+        assert_eq!(&a * a.inv(), FrElement::one());
+        assert_eq!(
+            (-FrElement::one()).representative(),
+            FrConfig::MODULUS - U256::from_u64(1)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::field::{
+    element::FieldElement,
+    extensions::{
+        cubic::{CubicExtensionField, HasCubicNonResidue},
+        quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
+    },
+    fields::montgomery_backed_prime_fields::{IsMontgomeryConfiguration, U256PrimeField},
+};
+use crate::unsigned_integer::element::U256;
+
+pub const BN254_PRIME_FIELD_ORDER: U256 =
+    U256::from("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+// FPBN254
+#[derive(Clone, Debug)]
+pub struct BN254FieldConfig;
+impl IsMontgomeryConfiguration<4> for BN254FieldConfig {
+    const MODULUS: U256 = BN254_PRIME_FIELD_ORDER;
+}
+
+pub type BN254PrimeField = U256PrimeField<BN254FieldConfig>;
+
+/// `Fp2 = Fp[u] / (u^2 + 1)`.
+#[derive(Debug, Clone)]
+pub struct LevelOneResidue;
+impl HasQuadraticNonResidue for LevelOneResidue {
+    type BaseField = BN254PrimeField;
+
+    fn residue() -> FieldElement<BN254PrimeField> {
+        -FieldElement::one()
+    }
+}
+
+pub type LevelOneField = QuadraticExtensionField<LevelOneResidue>;
+
+/// `Fp6 = Fp2[v] / (v^3 - (9 + u))`.
+#[derive(Debug, Clone)]
+pub struct LevelTwoResidue;
+impl HasCubicNonResidue for LevelTwoResidue {
+    type BaseField = LevelOneField;
+
+    fn residue() -> FieldElement<LevelOneField> {
+        FieldElement::new([FieldElement::from(9), FieldElement::from(1)])
+    }
+}
+
+pub type LevelTwoField = CubicExtensionField<LevelTwoResidue>;
+
+/// `Fp12 = Fp6[w] / (w^2 - v)`.
+#[derive(Debug, Clone)]
+pub struct LevelThreeResidue;
+impl HasQuadraticNonResidue for LevelThreeResidue {
+    type BaseField = LevelTwoField;
+
+    fn residue() -> FieldElement<LevelTwoField> {
+        FieldElement::new([
+            FieldElement::zero(),
+            FieldElement::one(),
+            FieldElement::zero(),
+        ])
+    }
+}
+
+pub type Order12ExtensionField = QuadraticExtensionField<LevelThreeResidue>;
+
+impl FieldElement<BN254PrimeField> {
+    pub fn new_base(a_hex: &str) -> Self {
+        Self::new(U256::from(a_hex))
+    }
+}
+
+impl FieldElement<Order12ExtensionField> {
+    pub fn new_base(a_hex: &str) -> Self {
+        Self::new([
+            FieldElement::new([
+                FieldElement::new([FieldElement::new(U256::from(a_hex)), FieldElement::zero()]),
+                FieldElement::zero(),
+                FieldElement::zero(),
+            ]),
+            FieldElement::zero(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Fp12E = FieldElement<Order12ExtensionField>;
+
+    #[test]
+    fn nonresidue_of_the_last_level_is_a_sixth_root_of_nine_plus_u() {
+        let w = Fp12E::new([FieldElement::zero(), FieldElement::one()]);
+        let xi = Fp12E::new([
+            FieldElement::new([
+                FieldElement::new([FieldElement::from(9), FieldElement::from(1)]),
+                FieldElement::zero(),
+                FieldElement::zero(),
+            ]),
+            FieldElement::zero(),
+        ]);
+        assert_eq!(w.pow(6_u16), xi);
+    }
+
+    #[test]
+    fn base_field_modulus_minus_one_is_minus_one() {
+        let minus_one = FieldElement::<BN254PrimeField>::new_base(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46",
+        );
+        assert_eq!(minus_one, -FieldElement::one());
+    }
+}
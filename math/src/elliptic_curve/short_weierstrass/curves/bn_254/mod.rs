@@ -0,0 +1,5 @@
+pub mod curve;
+pub mod default_types;
+pub mod field_extension;
+pub mod pairing;
+pub mod twist;
@@ -0,0 +1,420 @@
+use super::{
+    curve::{BN254Curve, MILLER_LOOP_CONSTANT, X},
+    field_extension::{BN254PrimeField, LevelOneField, LevelTwoField, Order12ExtensionField},
+    twist::BN254TwistCurve,
+};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::point::ShortWeierstrassProjectivePoint, traits::IsPairing,
+    },
+    field::{element::FieldElement, errors::FieldError},
+};
+
+type G1Point = ShortWeierstrassProjectivePoint<BN254Curve>;
+type G2Point = ShortWeierstrassProjectivePoint<BN254TwistCurve>;
+
+/// The optimal ate pairing of BN254, with G1 in `BN254Curve` and G2
+/// in its sextic twist `BN254TwistCurve`. It agrees with the pairing of the
+/// Ethereum precompile specified in EIP-197.
+#[derive(Clone, Debug)]
+pub struct BN254AtePairing;
+
+impl IsPairing for BN254AtePairing {
+    type G1Point = G1Point;
+    type G2Point = G2Point;
+    type OutputField = Order12ExtensionField;
+
+    /// Computes the product of the optimal ate pairings of `pairs` with a single
+    /// final exponentiation.
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> Result<FieldElement<Self::OutputField>, FieldError> {
+        let mut result = FieldElement::one();
+        for (p, q) in pairs {
+            if !p.is_neutral_element() && !q.is_neutral_element() {
+                let p = p.try_to_affine()?;
+                let q = q.try_to_affine()?;
+                result = result * miller(&q, &p)?;
+            }
+        }
+        final_exponentiation(&result)
+    }
+}
+
+/// Evaluates at `p` the line through the point `(xt, yt)` of the twist with slope `lambda`.
+/// On the untwisted points the slope is `lambda * w` and the line evaluates to the sparse
+/// element `yp - lambda * xp * w + (lambda * xt - yt) * w^3`.
+fn line(
+    xt: &FieldElement<LevelOneField>,
+    yt: &FieldElement<LevelOneField>,
+    lambda: &FieldElement<LevelOneField>,
+    p: &G1Point,
+) -> FieldElement<Order12ExtensionField> {
+    let [xp, yp, _] = p.coordinates();
+    let xp = FieldElement::<LevelOneField>::new([xp.clone(), FieldElement::zero()]);
+    let yp = FieldElement::<LevelOneField>::new([yp.clone(), FieldElement::zero()]);
+    FieldElement::new([
+        FieldElement::new([yp, FieldElement::zero(), FieldElement::zero()]),
+        FieldElement::new([-lambda * xp, lambda * xt - yt, FieldElement::zero()]),
+    ])
+}
+
+/// Adds the affine point `(xq, yq)` to `(xt, yt)` and multiplies `f` by the line through them.
+/// When the points are opposite the line is vertical. Its value lies in `LevelTwoField`, which
+/// is cleared by the final exponentiation, so `f` is left unchanged.
+fn addition_step(
+    f: &mut FieldElement<Order12ExtensionField>,
+    xt: &mut FieldElement<LevelOneField>,
+    yt: &mut FieldElement<LevelOneField>,
+    xq: &FieldElement<LevelOneField>,
+    yq: &FieldElement<LevelOneField>,
+    p: &G1Point,
+) -> Result<(), FieldError> {
+    if xq == xt {
+        return Ok(());
+    }
+    let lambda = (yq - &*yt).try_div(&(xq - &*xt))?;
+    *f = &*f * line(xt, yt, &lambda, p);
+    let x = lambda.pow(2_u16) - &*xt - xq;
+    *yt = lambda * (&*xt - &x) - &*yt;
+    *xt = x;
+    Ok(())
+}
+
+/// Computes Miller's loop of the optimal ate pairing over the bits of `6x + 2`, followed by
+/// the additions of `pi(q)` and `-pi^2(q)`, where `pi` is the Frobenius endomorphism.
+/// Both `q` and `p` must be in affine coordinates and different from the point at infinity.
+/// See "High-Speed Software Implementation of the Optimal Ate Pairing over Barreto–Naehrig
+/// Curves" (https://eprint.iacr.org/2010/354), Algorithm 1.
+fn miller(q: &G2Point, p: &G1Point) -> Result<FieldElement<Order12ExtensionField>, FieldError> {
+    let [xq, yq, _] = q.coordinates();
+    let (mut xt, mut yt) = (xq.clone(), yq.clone());
+    let mut f = FieldElement::one();
+    let two = FieldElement::from(2);
+    let three = FieldElement::from(3);
+
+    let bits = 128 - MILLER_LOOP_CONSTANT.leading_zeros();
+    for i in (0..bits - 1).rev() {
+        let lambda = (&three * xt.pow(2_u16)).try_div(&(&two * &yt))?;
+        f = f.pow(2_u16) * line(&xt, &yt, &lambda, p);
+        let x = lambda.pow(2_u16) - &two * &xt;
+        yt = lambda * (xt - &x) - yt;
+        xt = x;
+
+        if (MILLER_LOOP_CONSTANT >> i) & 1 == 1 {
+            addition_step(&mut f, &mut xt, &mut yt, xq, yq, p)?;
+        }
+    }
+
+    let [xq1, yq1] = twist_frobenius(xq, yq);
+    addition_step(&mut f, &mut xt, &mut yt, &xq1, &yq1, p)?;
+    let [xq2, yq2] = twist_frobenius_square(xq, yq);
+    addition_step(&mut f, &mut xt, &mut yt, &xq2, &-yq2, p)?;
+    Ok(f)
+}
+
+/// Raises `f` to the power `(p^12 - 1) / r`.
+/// The easy part computes `f^((p^6 - 1) * (p^2 + 1))`. The hard part computes
+/// `f^((p^4 - p^2 + 1) / r)` with the addition chain of "On the Final Exponentiation
+/// for Calculating Pairings on Ordinary Elliptic Curves" (https://eprint.iacr.org/2008/490).
+fn final_exponentiation(
+    f: &FieldElement<Order12ExtensionField>,
+) -> Result<FieldElement<Order12ExtensionField>, FieldError> {
+    // Easy part
+    let f_easy_aux = conjugate(f) * f.try_inv()?;
+    let f = frobenius_square(&f_easy_aux) * &f_easy_aux;
+
+    // Hard part
+    let f_x = f.pow(X);
+    let f_x2 = f_x.pow(X);
+    let f_x3 = f_x2.pow(X);
+    let f_p2 = frobenius_square(&f);
+
+    let y0 = frobenius(&f) * &f_p2 * frobenius(&f_p2);
+    let y1 = conjugate(&f);
+    let y2 = frobenius_square(&f_x2);
+    let y3 = conjugate(&frobenius(&f_x));
+    let y4 = conjugate(&(&f_x * frobenius(&f_x2)));
+    let y5 = conjugate(&f_x2);
+    let y6 = conjugate(&(frobenius(&f_x3) * f_x3));
+
+    let t0 = y6.pow(2_u16) * y4 * &y5;
+    let t1 = y3 * y5 * &t0;
+    let t0 = t0 * y2;
+    let t1 = (t1.pow(2_u16) * t0).pow(2_u16);
+    let t0 = &t1 * y1;
+    let t1 = t1 * y0;
+    Ok(t0.pow(2_u16) * t1)
+}
+
+/// Returns `a0 - a1 * w` for `f = a0 + a1 * w`. This is `f^(p^6)`.
+fn conjugate(f: &FieldElement<Order12ExtensionField>) -> FieldElement<Order12ExtensionField> {
+    let [a0, a1] = f.value();
+    FieldElement::new([a0.clone(), -a1])
+}
+
+/// `(9 + u)^((p - 1) / 6)`.
+fn gamma_12() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BN254PrimeField>::new_base(
+            "1284b71c2865a7dfe8b99fdd76e68b605c521e08292f2176d60b35dadcc9e470",
+        ),
+        FieldElement::<BN254PrimeField>::new_base(
+            "246996f3b4fae7e6a6327cfe12150b8e747992778eeec7e5ca5cf05f80f362ac",
+        ),
+    ])
+}
+
+/// `(9 + u)^((p - 1) / 3)`.
+fn gamma_6() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BN254PrimeField>::new_base(
+            "2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d",
+        ),
+        FieldElement::<BN254PrimeField>::new_base(
+            "16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2",
+        ),
+    ])
+}
+
+/// `(9 + u)^(2 * (p - 1) / 3)`.
+fn gamma_6_square() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BN254PrimeField>::new_base(
+            "5b54f5e64eea80180f3c0b75a181e84d33365f7be94ec72848a1f55921ea762",
+        ),
+        FieldElement::<BN254PrimeField>::new_base(
+            "2c145edbe7fd8aee9f3a80b03b0b1c923685d2ea1bdec763c13b4711cd2b8126",
+        ),
+    ])
+}
+
+/// `(9 + u)^((p - 1) / 2)`.
+fn gamma_4() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BN254PrimeField>::new_base(
+            "63cf305489af5dcdc5ec698b6e2f9b9dbaae0eda9c95998dc54014671a0135a",
+        ),
+        FieldElement::<BN254PrimeField>::new_base(
+            "7c03cbcac41049a0704b5a7ec796f2b21807dc98fa25bd282d37f632623b0e3",
+        ),
+    ])
+}
+
+/// `(9 + u)^((p^2 - 1) / 3)`, which lies in the base field.
+fn gamma_6_frobenius_square() -> FieldElement<LevelOneField> {
+    FieldElement::new([
+        FieldElement::<BN254PrimeField>::new_base(
+            "30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48",
+        ),
+        FieldElement::zero(),
+    ])
+}
+
+/// Applies to the affine point `(x, y)` of the twist the map induced by
+/// the Frobenius endomorphism of the curve over `Order12ExtensionField`.
+fn twist_frobenius(
+    x: &FieldElement<LevelOneField>,
+    y: &FieldElement<LevelOneField>,
+) -> [FieldElement<LevelOneField>; 2] {
+    [
+        frobenius_level_one(x) * gamma_6(),
+        frobenius_level_one(y) * gamma_4(),
+    ]
+}
+
+/// Applies the map of `twist_frobenius` twice. Since `(9 + u)^((p^2 - 1) / 2) = -1`,
+/// the `y` coordinate is negated.
+fn twist_frobenius_square(
+    x: &FieldElement<LevelOneField>,
+    y: &FieldElement<LevelOneField>,
+) -> [FieldElement<LevelOneField>; 2] {
+    [x * gamma_6_frobenius_square(), -y]
+}
+
+fn frobenius_level_one(a: &FieldElement<LevelOneField>) -> FieldElement<LevelOneField> {
+    let [a0, a1] = a.value();
+    FieldElement::new([a0.clone(), -a1])
+}
+
+fn frobenius_level_two(a: &FieldElement<LevelTwoField>) -> FieldElement<LevelTwoField> {
+    let [a0, a1, a2] = a.value();
+    FieldElement::new([
+        frobenius_level_one(a0),
+        frobenius_level_one(a1) * gamma_6(),
+        frobenius_level_one(a2) * gamma_6_square(),
+    ])
+}
+
+/// Returns `f^p`.
+fn frobenius(f: &FieldElement<Order12ExtensionField>) -> FieldElement<Order12ExtensionField> {
+    let [a0, a1] = f.value();
+    let gamma = FieldElement::new([gamma_12(), FieldElement::zero(), FieldElement::zero()]);
+    FieldElement::new([frobenius_level_two(a0), frobenius_level_two(a1) * gamma])
+}
+
+/// Returns `f^(p^2)`.
+fn frobenius_square(
+    f: &FieldElement<Order12ExtensionField>,
+) -> FieldElement<Order12ExtensionField> {
+    frobenius(&frobenius(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::curves::bn_254::curve::SUBGROUP_ORDER, traits::IsEllipticCurve,
+        },
+        unsigned_integer::element::UnsignedInteger,
+    };
+
+    /// Decodes the input of the EIP-197 pairing check precompile. Each pair takes 192 bytes:
+    /// the coordinates `x`, `y` of the G1 point followed by the coordinates
+    /// `x_im`, `x_re`, `y_im`, `y_re` of the G2 point, all as 32-byte big-endian integers.
+    fn decode_eip_197_input(input: &str) -> Vec<(G1Point, G2Point)> {
+        let words: Vec<_> = (0..input.len())
+            .step_by(64)
+            .map(|i| FieldElement::<BN254PrimeField>::new_base(&input[i..i + 64]))
+            .collect();
+        words
+            .chunks(6)
+            .map(|w| {
+                let p = BN254Curve::create_point_from_affine(w[0].clone(), w[1].clone()).unwrap();
+                let q = BN254TwistCurve::create_point_from_affine(
+                    FieldElement::new([w[3].clone(), w[2].clone()]),
+                    FieldElement::new([w[5].clone(), w[4].clone()]),
+                )
+                .unwrap();
+                (p, q)
+            })
+            .collect()
+    }
+
+    fn eip_197_pairing_check(input: &str) -> bool {
+        let pairs = decode_eip_197_input(input);
+        let pairs: Vec<_> = pairs.iter().map(|(p, q)| (p, q)).collect();
+        BN254AtePairing::compute_batch(&pairs).unwrap() == FieldElement::one()
+    }
+
+    #[test]
+    fn eip_197_vector_jeff1() {
+        let input = "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f593034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf704bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a416782bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+        assert!(eip_197_pairing_check(input));
+    }
+
+    #[test]
+    fn eip_197_vector_one_point_fails() {
+        let input = "00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+        assert!(!eip_197_pairing_check(input));
+    }
+
+    #[test]
+    fn eip_197_vector_two_point_match() {
+        let input = "00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa000000000000000000000000000000000000000000000000000000000000000130644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+        assert!(eip_197_pairing_check(input));
+    }
+
+    #[test]
+    fn batch_ate_pairing_bilinearity() {
+        let p = BN254Curve::generator();
+        let q = BN254TwistCurve::generator();
+        let a = 11_u128;
+        let b = 93_u128;
+        let result = BN254AtePairing::compute_batch(&[
+            (&p.operate_with_self(a), &q.operate_with_self(b)),
+            (&p.operate_with_self(a * b), &q.neg()),
+        ])
+        .unwrap();
+        assert_eq!(result, FieldElement::one());
+    }
+
+    #[test]
+    fn ate_pairing_bilinearity() {
+        let p = BN254Curve::generator();
+        let q = BN254TwistCurve::generator();
+        let e = BN254AtePairing::compute(&p, &q).unwrap();
+        assert_eq!(
+            BN254AtePairing::compute(&p.operate_with_self(2_u128), &q).unwrap(),
+            e.pow(2_u16)
+        );
+        assert_eq!(
+            BN254AtePairing::compute(&p, &q.operate_with_self(3_u128)).unwrap(),
+            e.pow(3_u16)
+        );
+    }
+
+    #[test]
+    fn ate_pairing_is_non_degenerate() {
+        let p = BN254Curve::generator();
+        let q = BN254TwistCurve::generator();
+        let e = BN254AtePairing::compute(&p, &q).unwrap();
+        assert_ne!(e, FieldElement::one());
+        assert_eq!(e.pow(SUBGROUP_ORDER), FieldElement::one());
+    }
+
+    #[test]
+    fn ate_pairing_with_the_point_at_infinity_is_one() {
+        let p = BN254Curve::generator();
+        let q = BN254TwistCurve::generator();
+        assert_eq!(
+            BN254AtePairing::compute(&G1Point::neutral_element(), &q).unwrap(),
+            FieldElement::one()
+        );
+        assert_eq!(
+            BN254AtePairing::compute(&p, &G2Point::neutral_element()).unwrap(),
+            FieldElement::one()
+        );
+    }
+
+    #[test]
+    fn ate_pairing_with_a_malformed_point_is_an_error() {
+        // A point with `y = 0` outside the curve makes the doubling divide by zero.
+        let p = BN254Curve::generator();
+        let q = G2Point::new([
+            FieldElement::one(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        assert_eq!(
+            BN254AtePairing::compute(&p, &q),
+            Err(FieldError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn final_exponentiation_hard_part_matches_the_naive_exponentiation() {
+        // (p^4 - p^2 + 1) / r
+        let hard_part_exponent = UnsignedInteger::<12>::from("1baaa710b0759ad331ec15183177faf6c0eb522d5b122784e529a5861876f6b3b1b1355d189227d79581e16f3fd90c66b887d56d5095f23aaa441e3954bcf8adcc7b44c87cdbacff1154e7e1da014fd5abf5cc4f49c36d4e81bb482ccdf42b1");
+        let p = BN254Curve::generator();
+        let q = BN254TwistCurve::generator();
+        let f = miller(&q, &p).unwrap();
+        let f_easy_aux = conjugate(&f) * f.inv();
+        let f_easy = frobenius_square(&f_easy_aux) * &f_easy_aux;
+        assert_eq!(
+            final_exponentiation(&f).unwrap(),
+            f_easy.pow(hard_part_exponent)
+        );
+    }
+
+    #[test]
+    fn frobenius_is_the_p_power() {
+        let [x, y] = BN254TwistCurve::generator().untwist();
+        let f = x * y;
+        let p = UnsignedInteger::<4>::from(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+        );
+        assert_eq!(frobenius(&f), f.pow(p));
+    }
+
+    #[test]
+    fn twist_frobenius_matches_the_frobenius_of_the_untwisted_point() {
+        let q = BN254TwistCurve::generator();
+        let [x, y] = q.untwist();
+        let [xq1, yq1] = twist_frobenius(q.x(), q.y());
+        let q1 = BN254TwistCurve::create_point_from_affine(xq1, yq1).unwrap();
+        assert_eq!(q1.untwist(), [frobenius(&x), frobenius(&y)]);
+    }
+}
@@ -0,0 +1,163 @@
+use super::default_types::FrField;
+use super::field_extension::{BN254PrimeField, LevelOneField, Order12ExtensionField};
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::U256;
+use crate::{
+    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+};
+
+/// The sextic twist `y^2 = x^3 + 3 / (9 + u)` of BN254 over the quadratic
+/// extension `LevelOneField`. Its subgroup of order `r` is the G2 of the pairing.
+#[derive(Clone, Debug)]
+pub struct BN254TwistCurve;
+
+impl IsEllipticCurve for BN254TwistCurve {
+    type BaseField = LevelOneField;
+    type ScalarField = FrField;
+    type Cofactor = U256;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::new([
+                FieldElement::<BN254PrimeField>::new_base(
+                    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                ),
+                FieldElement::<BN254PrimeField>::new_base(
+                    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                ),
+            ]),
+            FieldElement::new([
+                FieldElement::<BN254PrimeField>::new_base(
+                    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+                ),
+                FieldElement::<BN254PrimeField>::new_base(
+                    "90689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                ),
+            ]),
+            FieldElement::one(),
+        ])
+    }
+
+    /// `2p - r`, where `p` is the order of the base field.
+    fn cofactor() -> U256 {
+        U256::from("30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d")
+    }
+}
+
+impl IsShortWeierstrass for BN254TwistCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(0)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::new([
+            FieldElement::<BN254PrimeField>::new_base(
+                "2b149d40ceb8aaae81be18991be06ac3b5b4c5e559dbefa33267e6dc24a138e5",
+            ),
+            FieldElement::<BN254PrimeField>::new_base(
+                "9713b03af0fed4cd2cafadeed8fdf4a74fa084e52d1852e4a2bd0685c315d2",
+            ),
+        ])
+    }
+}
+
+impl ShortWeierstrassProjectivePoint<BN254TwistCurve> {
+    /// Maps a point of the twist to the curve `y^2 = x^3 + 3` over
+    /// `Order12ExtensionField` and returns its affine coordinates.
+    /// The map is `(x, y) -> (x * w^2, y * w^3)`, where `w` is the generator
+    /// of `Order12ExtensionField` over `LevelTwoField` and `w^6 = 9 + u`.
+    /// Panics if `self` is the point at infinity.
+    pub fn untwist(&self) -> [FieldElement<Order12ExtensionField>; 2] {
+        let p = self.to_affine();
+        let w =
+            FieldElement::<Order12ExtensionField>::new([FieldElement::zero(), FieldElement::one()]);
+        let x = FieldElement::<Order12ExtensionField>::new([
+            FieldElement::new([p.x().clone(), FieldElement::zero(), FieldElement::zero()]),
+            FieldElement::zero(),
+        ]);
+        let y = FieldElement::<Order12ExtensionField>::new([
+            FieldElement::new([p.y().clone(), FieldElement::zero(), FieldElement::zero()]),
+            FieldElement::zero(),
+        ]);
+        [x * w.pow(2_u16), y * w.pow(3_u16)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::short_weierstrass::test_points;
+    use crate::{
+        cyclic_group::{IsGroup, IsGroupWithNegation},
+        elliptic_curve::{
+            short_weierstrass::curves::bn_254::curve::SUBGROUP_ORDER, traits::EllipticCurveError,
+        },
+    };
+
+    #[test]
+    fn generator_belongs_to_the_twist() {
+        let g = BN254TwistCurve::generator();
+        assert_eq!(
+            BN254TwistCurve::defining_equation(g.coordinates()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn b_times_nine_plus_u_is_three() {
+        let xi = FieldElement::<LevelOneField>::new([FieldElement::from(9), FieldElement::from(1)]);
+        assert_eq!(BN254TwistCurve::b() * xi, FieldElement::from(3));
+    }
+
+    #[test]
+    fn create_invalid_points_returns_an_error() {
+        assert_eq!(
+            BN254TwistCurve::create_point_from_affine(FieldElement::from(0), FieldElement::from(1)),
+            Err(EllipticCurveError::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn operate_with_self_works_1() {
+        let g = BN254TwistCurve::generator();
+        assert_eq!(
+            g.operate_with(&g).operate_with(&g),
+            g.operate_with_self(3_u128)
+        );
+    }
+
+    #[test]
+    fn generator_has_the_order_of_the_subgroup() {
+        let g = BN254TwistCurve::generator();
+        assert!(g
+            .operate_with_self_wnaf(SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn subgroup_checks_and_cofactor_clearing_work() {
+        let g = BN254TwistCurve::generator();
+        assert!(BN254TwistCurve::is_in_subgroup(&g));
+        let p = test_points::point_not_in_the_subgroup::<BN254TwistCurve>(
+            |k| FieldElement::new([FieldElement::from(k), FieldElement::one()]),
+            |x| x.sqrt(),
+        );
+        assert!(!BN254TwistCurve::is_in_subgroup(&p));
+        assert!(BN254TwistCurve::is_in_subgroup(
+            &BN254TwistCurve::clear_cofactor(&p)
+        ));
+        let [x, y, _] = p.to_affine().coordinates().clone();
+        assert_eq!(
+            BN254TwistCurve::create_point_in_subgroup_from_affine(x, y),
+            Err(EllipticCurveError::PointNotInSubgroup)
+        );
+    }
+
+    #[test]
+    fn untwisted_generator_belongs_to_the_curve_over_order_12_extension() {
+        let [x, y] = BN254TwistCurve::generator().untwist();
+        assert_eq!(y.pow(2_u16), x.pow(3_u16) + FieldElement::from(3));
+    }
+}
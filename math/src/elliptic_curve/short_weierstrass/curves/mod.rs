@@ -1,4 +1,5 @@
 pub mod bls12_377;
 pub mod bls12_381;
+pub mod bn_254;
 pub mod test_curve_1;
 pub mod test_curve_2;
@@ -38,6 +38,8 @@ pub struct TestCurve1;
 
 impl IsEllipticCurve for TestCurve1 {
     type BaseField = QuadraticExtensionField<TestCurveQuadraticNonResidue>;
+    type ScalarField = U64PrimeField<TEST_CURVE_1_MAIN_SUBGROUP_ORDER>;
+    type Cofactor = u64;
     type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
 
     fn generator() -> Self::PointRepresentation {
@@ -47,6 +49,11 @@ impl IsEllipticCurve for TestCurve1 {
             FieldElement::one(),
         ])
     }
+
+    /// The curve has `(59 + 1)^2` points over the quadratic extension.
+    fn cofactor() -> u64 {
+        720
+    }
 }
 
 impl IsShortWeierstrass for TestCurve1 {
@@ -1,7 +1,7 @@
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
 use crate::elliptic_curve::traits::IsEllipticCurve;
-use crate::field::fields::u384_prime_field::{
-    IsMontgomeryConfiguration, MontgomeryBackendPrimeField,
+use crate::field::fields::montgomery_backed_prime_fields::{
+    IsMontgomeryConfiguration, U384PrimeField,
 };
 use crate::unsigned_integer::element::U384;
 use crate::{
@@ -21,13 +21,20 @@ pub const TEST_CURVE_2_MAIN_SUBGROUP_ORDER: U384 = U384::from("40a065fb5a76390de
 // FPBLS12381
 #[derive(Clone, Debug)]
 pub struct TestCurve2MontgomeryConfig;
-impl IsMontgomeryConfiguration for TestCurve2MontgomeryConfig {
+impl IsMontgomeryConfiguration<6> for TestCurve2MontgomeryConfig {
     const MODULUS: U384 = TEST_CURVE_2_PRIME_FIELD_ORDER;
-    const MP: u64 = 1901108026836139985;
-    const R2: U384 = U384::from("f60e53d42ca85ba186067660c4f2daa94");
 }
 
-type TestCurve2PrimeField = MontgomeryBackendPrimeField<TestCurve2MontgomeryConfig>;
+type TestCurve2PrimeField = U384PrimeField<TestCurve2MontgomeryConfig>;
+
+#[derive(Clone, Debug)]
+pub struct TestCurve2ScalarFieldConfig;
+impl IsMontgomeryConfiguration<6> for TestCurve2ScalarFieldConfig {
+    const MODULUS: U384 = TEST_CURVE_2_MAIN_SUBGROUP_ORDER;
+}
+
+/// The field of the scalars of the main subgroup of the curve.
+pub type TestCurve2ScalarField = U384PrimeField<TestCurve2ScalarFieldConfig>;
 
 /// In F59 the element -1 is not a square. We use this property
 /// to construct a Quadratic Field Extension out of it by adding
@@ -48,6 +55,8 @@ pub struct TestCurve2;
 
 impl IsEllipticCurve for TestCurve2 {
     type BaseField = QuadraticExtensionField<TestCurve2QuadraticNonResidue>;
+    type ScalarField = TestCurve2ScalarField;
+    type Cofactor = U384;
     type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
 
     fn generator() -> Self::PointRepresentation {
@@ -63,6 +72,11 @@ impl IsEllipticCurve for TestCurve2 {
             FieldElement::one(),
         ])
     }
+
+    /// The curve is supersingular, so it has `(p + 1)^2` points over the quadratic extension.
+    fn cofactor() -> U384 {
+        U384::from("6da3f5af04b6052c4c53958c21a6e652e10bd24100")
+    }
 }
 
 impl IsShortWeierstrass for TestCurve2 {
@@ -0,0 +1,252 @@
+use crate::{
+    cyclic_group::{IsGroup, IsGroupWithNegation},
+    elliptic_curve::traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+    field::{element::FieldElement, errors::FieldError},
+};
+
+use super::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass};
+
+/// Represents an elliptic curve point using Jacobian coordinates [x: y: z],
+/// which correspond to the affine point (x / z^2, y / z^3).
+/// Points with `z = 0` represent the point at infinity.
+#[derive(Clone, Debug)]
+pub struct ShortWeierstrassJacobianPoint<E: IsEllipticCurve> {
+    pub value: [FieldElement<E::BaseField>; 3],
+}
+
+impl<E: IsEllipticCurve> ShortWeierstrassJacobianPoint<E> {
+    /// Creates an elliptic curve point giving the Jacobian [x: y: z] coordinates.
+    pub fn new(value: [FieldElement<E::BaseField>; 3]) -> Self {
+        Self { value }
+    }
+
+    /// Returns the `x` coordinate of the point.
+    pub fn x(&self) -> &FieldElement<E::BaseField> {
+        &self.value[0]
+    }
+
+    /// Returns the `y` coordinate of the point.
+    pub fn y(&self) -> &FieldElement<E::BaseField> {
+        &self.value[1]
+    }
+
+    /// Returns the `z` coordinate of the point.
+    pub fn z(&self) -> &FieldElement<E::BaseField> {
+        &self.value[2]
+    }
+
+    /// Returns a tuple [x, y, z] with the coordinates of the point.
+    pub fn coordinates(&self) -> &[FieldElement<E::BaseField>; 3] {
+        &self.value
+    }
+
+    /// Creates the same point in affine coordinates. That is,
+    /// returns [x / z^2: y / z^3: 1] where `self` is [x: y: z].
+    /// Panics if `self` is the point at infinity.
+    pub fn to_affine(&self) -> Self {
+        self.try_to_affine()
+            .expect("the point at infinity has no affine coordinates")
+    }
+
+    /// Creates the same point in affine coordinates, or returns an error
+    /// if `self` is the point at infinity.
+    pub fn try_to_affine(&self) -> Result<Self, FieldError> {
+        let [x, y, z] = self.coordinates();
+        let z_inv = z.try_inv()?;
+        let z_inv_square = z_inv.pow(2_u16);
+        Ok(Self::new([
+            x * &z_inv_square,
+            y * z_inv_square * z_inv,
+            FieldElement::one(),
+        ]))
+    }
+
+    /// Returns the additive inverse of the point.
+    pub fn neg(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        Self::new([x.clone(), -y, z.clone()])
+    }
+}
+
+impl<E: IsShortWeierstrass> ShortWeierstrassJacobianPoint<E> {
+    /// Computes `2 * self`.
+    /// Taken from the EFD formulas "dbl-2009-l" for `a = 0` and "dbl-2007-bl" otherwise.
+    pub fn double(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        let xx = x.pow(2_u16);
+        let yy = y.pow(2_u16);
+        let yyyy = yy.pow(2_u16);
+        let s = FieldElement::from(2) * ((x + &yy).pow(2_u16) - &xx - &yyyy);
+        let z3;
+        let m = if E::a_is_zero() {
+            z3 = FieldElement::from(2) * y * z;
+            FieldElement::from(3) * xx
+        } else {
+            let zz = z.pow(2_u16);
+            z3 = (y + z).pow(2_u16) - yy - &zz;
+            FieldElement::from(3) * xx + E::a() * zz.pow(2_u16)
+        };
+        let x3 = m.pow(2_u16) - FieldElement::from(2) * &s;
+        let y3 = m * (s - &x3) - FieldElement::from(8) * yyyy;
+        Self::new([x3, y3, z3])
+    }
+
+    /// Computes the addition of `self` and `other`, where `other` is in affine
+    /// coordinates, that is, with `z = 1` or the point at infinity.
+    /// Taken from the EFD formula "madd-2007-bl".
+    pub fn operate_with_affine(&self, other: &Self) -> Self {
+        if other.is_neutral_element() {
+            return self.clone();
+        }
+        if self.is_neutral_element() {
+            return other.clone();
+        }
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, _] = other.coordinates();
+        let z1z1 = z1.pow(2_u16);
+        let u2 = x2 * &z1z1;
+        let s2 = y2 * z1 * &z1z1;
+        let h = u2 - x1;
+        let r = FieldElement::from(2) * (s2 - y1);
+        if h == FieldElement::zero() {
+            return if r == FieldElement::zero() {
+                self.double()
+            } else {
+                Self::neutral_element()
+            };
+        }
+        let hh = h.pow(2_u16);
+        let i = FieldElement::from(4) * &hh;
+        let j = &h * &i;
+        let v = x1 * i;
+        let x3 = r.pow(2_u16) - &j - FieldElement::from(2) * &v;
+        let y3 = r * (v - &x3) - FieldElement::from(2) * y1 * j;
+        let z3 = (z1 + h).pow(2_u16) - z1z1 - hh;
+        Self::new([x3, y3, z3])
+    }
+}
+
+impl<E: IsEllipticCurve> PartialEq for ShortWeierstrassJacobianPoint<E> {
+    fn eq(&self, other: &Self) -> bool {
+        let [px, py, pz] = self.coordinates();
+        let [qx, qy, qz] = other.coordinates();
+        let zero = FieldElement::zero();
+        if *pz == zero || *qz == zero {
+            return *pz == zero && *qz == zero;
+        }
+        let pz_square = pz.pow(2_u16);
+        let qz_square = qz.pow(2_u16);
+        (px * &qz_square == qx * &pz_square) && (py * qz_square * qz == qy * pz_square * pz)
+    }
+}
+
+impl<E: IsEllipticCurve> Eq for ShortWeierstrassJacobianPoint<E> {}
+
+impl<E: IsShortWeierstrass> FromAffine<E::BaseField> for ShortWeierstrassJacobianPoint<E> {
+    fn from_affine(
+        x: FieldElement<E::BaseField>,
+        y: FieldElement<E::BaseField>,
+    ) -> Result<Self, EllipticCurveError> {
+        let coordinates = [x, y, FieldElement::one()];
+        if E::defining_equation(&coordinates) != FieldElement::zero() {
+            Err(EllipticCurveError::InvalidPoint)
+        } else {
+            Ok(Self::new(coordinates))
+        }
+    }
+}
+
+impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassJacobianPoint<E> {
+    /// The point at infinity.
+    fn neutral_element() -> Self {
+        Self::new([
+            FieldElement::one(),
+            FieldElement::one(),
+            FieldElement::zero(),
+        ])
+    }
+
+    /// Computes the addition of `self` and `other`.
+    /// Taken from the EFD formula "add-2007-bl".
+    fn operate_with(&self, other: &Self) -> Self {
+        if other.is_neutral_element() {
+            return self.clone();
+        }
+        if self.is_neutral_element() {
+            return other.clone();
+        }
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+        let z1z1 = z1.pow(2_u16);
+        let z2z2 = z2.pow(2_u16);
+        let u1 = x1 * &z2z2;
+        let u2 = x2 * &z1z1;
+        let s1 = y1 * z2 * &z2z2;
+        let s2 = y2 * z1 * &z1z1;
+        let h = u2 - &u1;
+        let r = FieldElement::from(2) * (s2 - &s1);
+        if h == FieldElement::zero() {
+            return if r == FieldElement::zero() {
+                self.double()
+            } else {
+                Self::neutral_element()
+            };
+        }
+        let i = (FieldElement::from(2) * &h).pow(2_u16);
+        let j = &h * &i;
+        let v = u1 * i;
+        let x3 = r.pow(2_u16) - &j - FieldElement::from(2) * &v;
+        let y3 = r * (v - &x3) - FieldElement::from(2) * s1 * j;
+        let z3 = ((z1 + z2).pow(2_u16) - z1z1 - z2z2) * h;
+        Self::new([x3, y3, z3])
+    }
+}
+
+impl<E: IsShortWeierstrass> IsGroupWithNegation for ShortWeierstrassJacobianPoint<E> {
+    /// Returns the additive inverse of `self`.
+    fn neg(&self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassProjectivePoint<E>>
+    for ShortWeierstrassJacobianPoint<E>
+{
+    /// Maps the projective point [x: y: z] to the Jacobian point [x * z: y * z^2: z].
+    fn from(p: ShortWeierstrassProjectivePoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, z] = p.coordinates();
+        Self::new([x * z, y * z.pow(2_u16), z.clone()])
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassJacobianPoint<E>>
+    for ShortWeierstrassProjectivePoint<E>
+{
+    /// Maps the Jacobian point [x: y: z] to the projective point [x * z: y: z^3].
+    fn from(p: ShortWeierstrassJacobianPoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, z] = p.coordinates();
+        Self::new([x * z, y.clone(), z.pow(3_u16)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+
+    point_representation_tests!(ShortWeierstrassJacobianPoint);
+
+    #[test]
+    fn to_affine_sets_z_to_one() {
+        let p = ShortWeierstrassJacobianPoint::<BLS12381Curve>::from(
+            BLS12381Curve::generator().operate_with_self(6_u128),
+        );
+        assert_eq!(*p.to_affine().z(), FieldElement::one());
+    }
+}
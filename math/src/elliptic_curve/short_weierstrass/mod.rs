@@ -1,8 +1,129 @@
+/// Generates the tests shared by the point representations of short Weierstrass curves,
+/// given the name of a representation generic over the curve.
+/// It must be called inside a test module that imports the representation.
+#[cfg(test)]
+macro_rules! point_representation_tests {
+    ($point:ident) => {
+        mod representation {
+            use super::$point;
+            use crate::{
+                cyclic_group::IsGroup,
+                elliptic_curve::{
+                    short_weierstrass::{
+                        curves::{
+                            bls12_377::curve::BLS12377Curve, bls12_381::curve::BLS12381Curve,
+                            test_curve_1::TestCurve1,
+                        },
+                        point::ShortWeierstrassProjectivePoint,
+                        traits::IsShortWeierstrass,
+                    },
+                    traits::{EllipticCurveError, FromAffine},
+                },
+                field::element::FieldElement,
+            };
+
+            type G1Point = $point<BLS12381Curve>;
+
+            fn generator<E>() -> $point<E>
+            where
+                E: IsShortWeierstrass<PointRepresentation = ShortWeierstrassProjectivePoint<E>>,
+            {
+                E::generator().into()
+            }
+
+            fn assert_matches_projective<E>()
+            where
+                E: IsShortWeierstrass<PointRepresentation = ShortWeierstrassProjectivePoint<E>>,
+            {
+                let g = E::generator();
+                let g_representation = generator::<E>();
+                for k in 0..10_u128 {
+                    let expected = g.operate_with_self(k);
+                    let result = g_representation.operate_with_self(k);
+                    assert_eq!(
+                        ShortWeierstrassProjectivePoint::from(result.clone()),
+                        expected
+                    );
+                    assert_eq!(result, expected.into());
+                }
+            }
+
+            #[test]
+            fn operate_with_self_matches_the_projective_representation() {
+                assert_matches_projective::<BLS12381Curve>();
+                assert_matches_projective::<BLS12377Curve>();
+                assert_matches_projective::<TestCurve1>();
+            }
+
+            #[test]
+            fn doubling_matches_addition() {
+                let g = generator::<BLS12381Curve>().operate_with_self(7_u128);
+                assert_eq!(g.double(), g.operate_with(&g));
+                let h = generator::<TestCurve1>().operate_with_self(3_u128);
+                assert_eq!(h.double(), h.operate_with(&h));
+            }
+
+            #[test]
+            fn mixed_addition_matches_addition() {
+                let g = generator::<BLS12381Curve>();
+                let p = g.operate_with_self(5_u128);
+                let q = g.operate_with_self(9_u128).to_affine();
+                assert_eq!(p.operate_with_affine(&q), g.operate_with_self(14_u128));
+                assert_eq!(p.operate_with_affine(&p.to_affine()), p.double());
+                assert!(p
+                    .operate_with_affine(&p.neg().to_affine())
+                    .is_neutral_element());
+                assert_eq!(G1Point::neutral_element().operate_with_affine(&q), q);
+                assert_eq!(p.operate_with_affine(&G1Point::neutral_element()), p);
+            }
+
+            #[test]
+            fn adding_the_inverse_gives_the_point_at_infinity() {
+                let p = generator::<BLS12381Curve>().operate_with_self(3_u128);
+                assert!(p.operate_with(&p.neg()).is_neutral_element());
+            }
+
+            #[test]
+            fn to_affine_keeps_the_point() {
+                let p = generator::<BLS12377Curve>().operate_with_self(6_u128);
+                let affine = p.to_affine();
+                assert_eq!(affine, p);
+                assert_eq!(affine.to_affine().coordinates(), affine.coordinates());
+            }
+
+            #[test]
+            fn from_affine_rejects_points_not_in_the_curve() {
+                assert_eq!(
+                    G1Point::from_affine(FieldElement::from(0), FieldElement::from(1)),
+                    Err(EllipticCurveError::InvalidPoint)
+                );
+            }
+
+            #[test]
+            fn conversions_preserve_the_point_at_infinity() {
+                let infinity = ShortWeierstrassProjectivePoint::<BLS12381Curve>::neutral_element();
+                assert!(G1Point::from(infinity).is_neutral_element());
+                assert!(
+                    ShortWeierstrassProjectivePoint::from(G1Point::neutral_element())
+                        .is_neutral_element()
+                );
+            }
+        }
+    };
+}
+
 /// Implementation of particular cases of elliptic curves.
 pub mod curves;
+/// Points in Jacobian coordinates
+pub mod jacobian;
 /// Pairing algorithms
 pub mod pairings;
 /// Structs for points
 pub mod point;
+/// Points for test purposes.
+#[cfg(test)]
+pub(crate) mod test_points;
 /// Common behaviour for Elliptic curves.
 pub mod traits;
+/// Points in XYZZ coordinates
+pub mod xyzz;
@@ -1,11 +1,20 @@
+#[cfg(feature = "constant-time")]
+use crate::constant_time::{
+    Choice, ConditionallySelectable, IsConstantTimeField, IsConstantTimeGroup,
+};
 use crate::{
-    cyclic_group::IsGroup,
+    cyclic_group::{IsGroup, IsGroupWithNegation},
     elliptic_curve::{
         point::ProjectivePoint,
         traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
     },
-    field::element::FieldElement,
+    field::{element::FieldElement, errors::FieldError},
+    msm::msm,
+    traits::RandomSample,
+    unsigned_integer::element::UnsignedInteger,
 };
+use rand::Rng;
+use std::ops::Mul;
 
 use super::traits::IsShortWeierstrass;
 
@@ -45,6 +54,25 @@ impl<E: IsEllipticCurve> ShortWeierstrassProjectivePoint<E> {
         Self(self.0.to_affine())
     }
 
+    /// Creates the same point in affine coordinates, or returns an error
+    /// if `self` is the point at infinity.
+    pub fn try_to_affine(&self) -> Result<Self, FieldError> {
+        self.0.try_to_affine().map(Self)
+    }
+
+    /// Creates the same points in affine coordinates, using a single inversion
+    /// for all of them. Points at infinity are returned unchanged.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<Self> {
+        let points: Vec<_> = points
+            .iter()
+            .map(|point| ProjectivePoint::new(point.coordinates().clone()))
+            .collect();
+        ProjectivePoint::batch_to_affine(&points)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+
     /// Returns the additive inverse of the projective point `p`
     pub fn neg(&self) -> Self {
         let [px, py, pz] = self.coordinates();
@@ -126,3 +154,113 @@ impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassProjectivePoint<E> {
         }
     }
 }
+
+impl<E: IsShortWeierstrass> IsGroupWithNegation for ShortWeierstrassProjectivePoint<E> {
+    /// Returns the additive inverse of `self`.
+    fn neg(&self) -> Self {
+        Self::neg(self)
+    }
+}
+
+/// Samples a point of the subgroup generated by `E::generator()` as a random multiple
+/// of the generator. The scalar has 512 bits, so its reduction modulo the order of the
+/// subgroup is uniform up to a negligible bias.
+impl<E: IsShortWeierstrass<PointRepresentation = Self>> RandomSample
+    for ShortWeierstrassProjectivePoint<E>
+{
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let scalar = UnsignedInteger::<8>::random(rng);
+        msm(&[scalar], &[E::generator()])
+    }
+}
+
+/// Multiplies the point by a scalar of the scalar field of its curve.
+impl<E: IsShortWeierstrass> Mul<&FieldElement<E::ScalarField>>
+    for &ShortWeierstrassProjectivePoint<E>
+{
+    type Output = ShortWeierstrassProjectivePoint<E>;
+
+    fn mul(self, scalar: &FieldElement<E::ScalarField>) -> Self::Output {
+        self.operate_with_self_wnaf(scalar.representative())
+    }
+}
+
+impl<E: IsShortWeierstrass> Mul<FieldElement<E::ScalarField>>
+    for ShortWeierstrassProjectivePoint<E>
+{
+    type Output = ShortWeierstrassProjectivePoint<E>;
+
+    fn mul(self, scalar: FieldElement<E::ScalarField>) -> Self::Output {
+        &self * &scalar
+    }
+}
+
+impl<E: IsShortWeierstrass> Mul<&FieldElement<E::ScalarField>>
+    for ShortWeierstrassProjectivePoint<E>
+{
+    type Output = ShortWeierstrassProjectivePoint<E>;
+
+    fn mul(self, scalar: &FieldElement<E::ScalarField>) -> Self::Output {
+        &self * scalar
+    }
+}
+
+impl<E: IsShortWeierstrass> Mul<FieldElement<E::ScalarField>>
+    for &ShortWeierstrassProjectivePoint<E>
+{
+    type Output = ShortWeierstrassProjectivePoint<E>;
+
+    fn mul(self, scalar: FieldElement<E::ScalarField>) -> Self::Output {
+        self * &scalar
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<E: IsEllipticCurve> ConditionallySelectable for ShortWeierstrassProjectivePoint<E>
+where
+    FieldElement<E::BaseField>: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(ConditionallySelectable::conditional_select(
+            a.coordinates(),
+            b.coordinates(),
+            choice,
+        ))
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<E: IsShortWeierstrass> IsConstantTimeGroup for ShortWeierstrassProjectivePoint<E>
+where
+    E::BaseField: IsConstantTimeField,
+    FieldElement<E::BaseField>: ConditionallySelectable,
+{
+    /// Computes the addition of `self` and `other` with the complete formulas of
+    /// "Complete addition formulas for prime order elliptic curves"
+    /// (https://eprint.iacr.org/2015/1060), Algorithm 1. They hold for every pair
+    /// of points, including the point at infinity, on curves without points of order two.
+    fn operate_with_constant_time(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+        let a = E::a();
+        let b3 = FieldElement::from(3) * E::b();
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * (x2 + y2) - (&t0 + &t1);
+        let t4 = (x1 + z1) * (x2 + z2) - (&t0 + &t2);
+        let t5 = (y1 + z1) * (y2 + z2) - (&t1 + &t2);
+        let z3 = &a * &t4 + &b3 * &t2;
+        let x3 = &t1 - &z3;
+        let z3 = &t1 + &z3;
+        let y3 = &x3 * &z3;
+        let t1 = FieldElement::from(3) * &t0 + &a * &t2;
+        let t2 = &a * (&t0 - &a * &t2);
+        let t4 = b3 * t4 + t2;
+        let y3 = y3 + &t1 * &t4;
+        let x3 = &t3 * x3 - &t5 * &t4;
+        let z3 = t5 * z3 + t3 * t1;
+        Self::new([x3, y3, z3])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    cyclic_group::{IsGroup, IsGroupWithNegation},
+    field::{element::FieldElement, traits::IsPrimeField},
+};
+
+use super::traits::IsShortWeierstrass;
+
+/// Returns the first point of `E` with `x` coordinate `x_coordinate(k)` for `k = 1, 2, ...`
+/// that does not belong to the subgroup of order `r`. The square roots of the base field
+/// are taken with `sqrt`, since they are not part of the field traits.
+pub(crate) fn point_not_in_the_subgroup<E: IsShortWeierstrass>(
+    x_coordinate: impl Fn(u64) -> FieldElement<E::BaseField>,
+    sqrt: impl Fn(
+        &FieldElement<E::BaseField>,
+    ) -> Option<(FieldElement<E::BaseField>, FieldElement<E::BaseField>)>,
+) -> E::PointRepresentation {
+    (1..)
+        .filter_map(|k| {
+            let x = x_coordinate(k);
+            let (y, _) = sqrt(&(x.pow(3_u16) + E::a() * &x + E::b()))?;
+            E::create_point_from_affine(x, y).ok()
+        })
+        .find(|p| {
+            !p.operate_with_self_wnaf(E::ScalarField::modulus_minus_one())
+                .operate_with(p)
+                .is_neutral_element()
+        })
+        .unwrap()
+}
@@ -11,6 +11,12 @@ pub trait IsShortWeierstrass: IsEllipticCurve + Clone + Debug {
     /// `b` coefficient for the equation  `y^2 = x^3 + a * x  + b`.
     fn b() -> FieldElement<Self::BaseField>;
 
+    /// Returns `true` if the coefficient `a` is zero.
+    /// Point representations use it to pick cheaper doubling formulas.
+    fn a_is_zero() -> bool {
+        Self::a() == FieldElement::zero()
+    }
+
     /// Evaluates the short Weierstrass equation at (x, y z).
     /// Used for checking if [x: y: z] belongs to the elliptic curve.
     fn defining_equation(p: &[FieldElement<Self::BaseField>; 3]) -> FieldElement<Self::BaseField> {
@@ -0,0 +1,294 @@
+use crate::{
+    cyclic_group::{IsGroup, IsGroupWithNegation},
+    elliptic_curve::traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+    field::{element::FieldElement, errors::FieldError},
+};
+
+use super::{
+    jacobian::ShortWeierstrassJacobianPoint, point::ShortWeierstrassProjectivePoint,
+    traits::IsShortWeierstrass,
+};
+
+/// Represents an elliptic curve point using XYZZ coordinates [x: y: zz: zzz],
+/// which correspond to the affine point (x / zz, y / zzz) and satisfy `zz^3 = zzz^2`.
+/// Points with `zz = zzz = 0` represent the point at infinity.
+#[derive(Clone, Debug)]
+pub struct ShortWeierstrassXYZZPoint<E: IsEllipticCurve> {
+    pub value: [FieldElement<E::BaseField>; 4],
+}
+
+impl<E: IsEllipticCurve> ShortWeierstrassXYZZPoint<E> {
+    /// Creates an elliptic curve point giving the [x: y: zz: zzz] coordinates.
+    pub fn new(value: [FieldElement<E::BaseField>; 4]) -> Self {
+        Self { value }
+    }
+
+    /// Returns the `x` coordinate of the point.
+    pub fn x(&self) -> &FieldElement<E::BaseField> {
+        &self.value[0]
+    }
+
+    /// Returns the `y` coordinate of the point.
+    pub fn y(&self) -> &FieldElement<E::BaseField> {
+        &self.value[1]
+    }
+
+    /// Returns the `zz` coordinate of the point.
+    pub fn zz(&self) -> &FieldElement<E::BaseField> {
+        &self.value[2]
+    }
+
+    /// Returns the `zzz` coordinate of the point.
+    pub fn zzz(&self) -> &FieldElement<E::BaseField> {
+        &self.value[3]
+    }
+
+    /// Returns a tuple [x, y, zz, zzz] with the coordinates of the point.
+    pub fn coordinates(&self) -> &[FieldElement<E::BaseField>; 4] {
+        &self.value
+    }
+
+    /// Creates the same point in affine coordinates. That is,
+    /// returns [x / zz: y / zzz: 1: 1] where `self` is [x: y: zz: zzz].
+    /// Panics if `self` is the point at infinity.
+    pub fn to_affine(&self) -> Self {
+        self.try_to_affine()
+            .expect("the point at infinity has no affine coordinates")
+    }
+
+    /// Creates the same point in affine coordinates, or returns an error
+    /// if `self` is the point at infinity.
+    pub fn try_to_affine(&self) -> Result<Self, FieldError> {
+        let [x, y, zz, zzz] = self.coordinates();
+        Ok(Self::new([
+            x * zz.try_inv()?,
+            y * zzz.try_inv()?,
+            FieldElement::one(),
+            FieldElement::one(),
+        ]))
+    }
+
+    /// Returns the additive inverse of the point.
+    pub fn neg(&self) -> Self {
+        let [x, y, zz, zzz] = self.coordinates();
+        Self::new([x.clone(), -y, zz.clone(), zzz.clone()])
+    }
+}
+
+impl<E: IsShortWeierstrass> ShortWeierstrassXYZZPoint<E> {
+    /// Computes `2 * self`.
+    /// Taken from the EFD formula "dbl-2008-s-1".
+    pub fn double(&self) -> Self {
+        let [x, y, zz, zzz] = self.coordinates();
+        let u = FieldElement::from(2) * y;
+        let v = u.pow(2_u16);
+        let w = &u * &v;
+        let s = x * &v;
+        let m = if E::a_is_zero() {
+            FieldElement::from(3) * x.pow(2_u16)
+        } else {
+            FieldElement::from(3) * x.pow(2_u16) + E::a() * zz.pow(2_u16)
+        };
+        let x3 = m.pow(2_u16) - FieldElement::from(2) * &s;
+        let y3 = m * (s - &x3) - &w * y;
+        Self::new([x3, y3, v * zz, w * zzz])
+    }
+
+    /// Computes the addition of `self` and `other`, where `other` is in affine
+    /// coordinates, that is, with `zz = zzz = 1` or the point at infinity.
+    /// Taken from the EFD formula "madd-2008-s".
+    pub fn operate_with_affine(&self, other: &Self) -> Self {
+        if other.is_neutral_element() {
+            return self.clone();
+        }
+        if self.is_neutral_element() {
+            return other.clone();
+        }
+        let [x1, y1, zz1, zzz1] = self.coordinates();
+        let [x2, y2, _, _] = other.coordinates();
+        let p = x2 * zz1 - x1;
+        let r = y2 * zzz1 - y1;
+        if p == FieldElement::zero() {
+            return if r == FieldElement::zero() {
+                self.double()
+            } else {
+                Self::neutral_element()
+            };
+        }
+        let pp = p.pow(2_u16);
+        let ppp = &p * &pp;
+        let q = x1 * &pp;
+        let x3 = r.pow(2_u16) - &ppp - FieldElement::from(2) * &q;
+        let y3 = r * (q - &x3) - y1 * &ppp;
+        Self::new([x3, y3, zz1 * pp, zzz1 * ppp])
+    }
+}
+
+impl<E: IsEllipticCurve> PartialEq for ShortWeierstrassXYZZPoint<E> {
+    fn eq(&self, other: &Self) -> bool {
+        let [px, py, pzz, pzzz] = self.coordinates();
+        let [qx, qy, qzz, qzzz] = other.coordinates();
+        let zero = FieldElement::zero();
+        if *pzz == zero || *qzz == zero {
+            return *pzz == zero && *qzz == zero;
+        }
+        (px * qzz == qx * pzz) && (py * qzzz == qy * pzzz)
+    }
+}
+
+impl<E: IsEllipticCurve> Eq for ShortWeierstrassXYZZPoint<E> {}
+
+impl<E: IsShortWeierstrass> FromAffine<E::BaseField> for ShortWeierstrassXYZZPoint<E> {
+    fn from_affine(
+        x: FieldElement<E::BaseField>,
+        y: FieldElement<E::BaseField>,
+    ) -> Result<Self, EllipticCurveError> {
+        if E::defining_equation(&[x.clone(), y.clone(), FieldElement::one()])
+            != FieldElement::zero()
+        {
+            Err(EllipticCurveError::InvalidPoint)
+        } else {
+            Ok(Self::new([x, y, FieldElement::one(), FieldElement::one()]))
+        }
+    }
+}
+
+impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassXYZZPoint<E> {
+    /// The point at infinity.
+    fn neutral_element() -> Self {
+        Self::new([
+            FieldElement::one(),
+            FieldElement::one(),
+            FieldElement::zero(),
+            FieldElement::zero(),
+        ])
+    }
+
+    /// Computes the addition of `self` and `other`.
+    /// Taken from the EFD formula "add-2008-s".
+    fn operate_with(&self, other: &Self) -> Self {
+        if other.is_neutral_element() {
+            return self.clone();
+        }
+        if self.is_neutral_element() {
+            return other.clone();
+        }
+        let [x1, y1, zz1, zzz1] = self.coordinates();
+        let [x2, y2, zz2, zzz2] = other.coordinates();
+        let u1 = x1 * zz2;
+        let s1 = y1 * zzz2;
+        let p = x2 * zz1 - &u1;
+        let r = y2 * zzz1 - &s1;
+        if p == FieldElement::zero() {
+            return if r == FieldElement::zero() {
+                self.double()
+            } else {
+                Self::neutral_element()
+            };
+        }
+        let pp = p.pow(2_u16);
+        let ppp = &p * &pp;
+        let q = u1 * &pp;
+        let x3 = r.pow(2_u16) - &ppp - FieldElement::from(2) * &q;
+        let y3 = r * (q - &x3) - s1 * &ppp;
+        Self::new([x3, y3, zz1 * zz2 * pp, zzz1 * zzz2 * ppp])
+    }
+}
+
+impl<E: IsShortWeierstrass> IsGroupWithNegation for ShortWeierstrassXYZZPoint<E> {
+    /// Returns the additive inverse of `self`.
+    fn neg(&self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassProjectivePoint<E>>
+    for ShortWeierstrassXYZZPoint<E>
+{
+    /// Maps the projective point [x: y: z] to [x * z: y * z^2: z^2: z^3].
+    fn from(p: ShortWeierstrassProjectivePoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, z] = p.coordinates();
+        let zz = z.pow(2_u16);
+        let zzz = &zz * z;
+        Self::new([x * z, y * &zz, zz, zzz])
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassXYZZPoint<E>>
+    for ShortWeierstrassProjectivePoint<E>
+{
+    /// Maps [x: y: zz: zzz] to the projective point [x * zzz: y * zz: zz * zzz].
+    fn from(p: ShortWeierstrassXYZZPoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, zz, zzz] = p.coordinates();
+        Self::new([x * zzz, y * zz, zz * zzz])
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassJacobianPoint<E>>
+    for ShortWeierstrassXYZZPoint<E>
+{
+    /// Maps the Jacobian point [x: y: z] to [x: y: z^2: z^3].
+    fn from(p: ShortWeierstrassJacobianPoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, z] = p.coordinates();
+        let zz = z.pow(2_u16);
+        let zzz = &zz * z;
+        Self::new([x.clone(), y.clone(), zz, zzz])
+    }
+}
+
+impl<E: IsShortWeierstrass> From<ShortWeierstrassXYZZPoint<E>>
+    for ShortWeierstrassJacobianPoint<E>
+{
+    /// Maps [x: y: zz: zzz] to the Jacobian point [x * zz^2: y * zzz^2: zzz].
+    /// This uses the relation `zz^3 = zzz^2`.
+    fn from(p: ShortWeierstrassXYZZPoint<E>) -> Self {
+        if p.is_neutral_element() {
+            return Self::neutral_element();
+        }
+        let [x, y, zz, zzz] = p.coordinates();
+        Self::new([x * zz.pow(2_u16), y * zzz.pow(2_u16), zzz.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::short_weierstrass::curves::{
+        bls12_377::curve::BLS12377Curve, bls12_381::curve::BLS12381Curve,
+    };
+
+    point_representation_tests!(ShortWeierstrassXYZZPoint);
+
+    #[test]
+    fn to_affine_sets_zz_and_zzz_to_one() {
+        let p = ShortWeierstrassXYZZPoint::<BLS12381Curve>::from(
+            BLS12381Curve::generator().operate_with_self(6_u128),
+        );
+        let affine = p.to_affine();
+        assert_eq!(*affine.zz(), FieldElement::one());
+        assert_eq!(*affine.zzz(), FieldElement::one());
+    }
+
+    #[test]
+    fn conversion_from_and_to_jacobian_preserves_the_point() {
+        let p = ShortWeierstrassXYZZPoint::<BLS12377Curve>::from(
+            BLS12377Curve::generator().operate_with_self(6_u128),
+        );
+        let jacobian = ShortWeierstrassJacobianPoint::from(p.clone());
+        assert_eq!(ShortWeierstrassXYZZPoint::from(jacobian.clone()), p);
+        assert_eq!(jacobian.operate_with(&jacobian), p.double().into());
+        assert!(ShortWeierstrassJacobianPoint::from(
+            ShortWeierstrassXYZZPoint::<BLS12381Curve>::neutral_element()
+        )
+        .is_neutral_element());
+    }
+}
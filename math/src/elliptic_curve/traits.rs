@@ -1,12 +1,18 @@
 use crate::{
-    cyclic_group::IsGroup,
-    field::{element::FieldElement, traits::IsField},
+    cyclic_group::{IsGroup, IsGroupWithNegation},
+    field::{
+        element::FieldElement,
+        errors::FieldError,
+        traits::{IsField, IsPrimeField},
+    },
+    unsigned_integer::traits::IsUnsignedInteger,
 };
 use std::fmt::Debug;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum EllipticCurveError {
     InvalidPoint,
+    PointNotInSubgroup,
 }
 
 pub trait IsEllipticCurve {
@@ -14,14 +20,25 @@ pub trait IsEllipticCurve {
     /// belonging to the curve.
     type BaseField: IsField + Clone + Debug;
 
+    /// ScalarField is the field of the scalars that multiply the points of
+    /// the main subgroup. Its order is the order of that subgroup.
+    type ScalarField: IsPrimeField + Clone + Debug;
+
+    /// The type of the cofactor, large enough to hold it.
+    type Cofactor: IsUnsignedInteger;
+
     /// The representation of the point. For example it can be projective
     /// coordinates, affine coordinates, XYZZ, depending on the curve and its
     /// possible optimizations.
-    type PointRepresentation: IsGroup + FromAffine<Self::BaseField>;
+    type PointRepresentation: IsGroupWithNegation + FromAffine<Self::BaseField>;
 
     /// Returns the generator of the main subgroup.
     fn generator() -> Self::PointRepresentation;
 
+    /// Returns the cofactor, which is the number of points of the curve
+    /// divided by the order of the main subgroup.
+    fn cofactor() -> Self::Cofactor;
+
     /// Returns an affine point.
     fn create_point_from_affine(
         x: FieldElement<Self::BaseField>,
@@ -29,8 +46,58 @@ pub trait IsEllipticCurve {
     ) -> Result<Self::PointRepresentation, EllipticCurveError> {
         Self::PointRepresentation::from_affine(x, y)
     }
+
+    /// Returns an affine point of the main subgroup. Unlike `create_point_from_affine`,
+    /// it rejects the points of the curve that do not belong to that subgroup.
+    fn create_point_in_subgroup_from_affine(
+        x: FieldElement<Self::BaseField>,
+        y: FieldElement<Self::BaseField>,
+    ) -> Result<Self::PointRepresentation, EllipticCurveError> {
+        let point = Self::create_point_from_affine(x, y)?;
+        if Self::is_in_subgroup(&point) {
+            Ok(point)
+        } else {
+            Err(EllipticCurveError::PointNotInSubgroup)
+        }
+    }
+
+    /// Returns whether `p` belongs to the main subgroup, of prime order `r`.
+    /// The default checks that `[r]p` is the neutral element. Curves with an
+    /// efficient endomorphism can override it with a faster check.
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        // `r` is the order of the scalar field, which only represents up to `r - 1`.
+        p.operate_with_self_wnaf(Self::ScalarField::modulus_minus_one())
+            .operate_with(p)
+            .is_neutral_element()
+    }
+
+    /// Maps `p` to the main subgroup. The default multiplies it by the cofactor.
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.operate_with_self_wnaf(Self::cofactor())
+    }
 }
 
 pub trait FromAffine<F: IsField>: Sized {
     fn from_affine(x: FieldElement<F>, y: FieldElement<F>) -> Result<Self, EllipticCurveError>;
 }
+
+pub trait IsPairing {
+    type G1Point: IsGroup;
+    type G2Point: IsGroup;
+    type OutputField: IsField;
+
+    /// Compute the product of the pairings for a list of point pairs.
+    /// Returns an error if a malformed point leads to a division by zero.
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> Result<FieldElement<Self::OutputField>, FieldError>;
+
+    /// Compute the pairing between point `p` of the first group
+    /// and point `q` of the second group.
+    fn compute(
+        p: &Self::G1Point,
+        q: &Self::G2Point,
+    ) -> Result<FieldElement<Self::OutputField>, FieldError> {
+        Self::compute_batch(&[(p, q)])
+    }
+}
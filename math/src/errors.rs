@@ -1,9 +1,37 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum ByteConversionError {
     #[error("from_be_bytes failed")]
     FromBEBytesError,
     #[error("from_le_bytes failed")]
     FromLEBytesError,
+    #[error("the encoding does not have the expected length")]
+    InvalidLength,
+    #[error("the flag bits of the encoding are invalid")]
+    InvalidFlags,
+    #[error("the encoded value is not smaller than the modulus")]
+    ValueNotReduced,
+    #[error("the encoded point does not belong to the curve")]
+    PointNotInCurve,
+    #[error("the encoded point does not belong to the subgroup")]
+    PointNotInSubgroup,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseUnsignedIntegerError {
+    #[error("cannot parse an integer from an empty string")]
+    Empty,
+    #[error("invalid digit found in string")]
+    InvalidDigit,
+    #[error("the number does not fit in the limbs of the integer")]
+    Overflow,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseFieldElementError {
+    #[error(transparent)]
+    InvalidInteger(#[from] ParseUnsignedIntegerError),
+    #[error("the number is not smaller than the modulus")]
+    NotReduced,
 }
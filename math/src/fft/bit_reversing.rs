@@ -0,0 +1,52 @@
+/// Reorders `input` in place so that the element at index `i` moves to the index
+/// obtained by reversing the `log2(input.len())` bits of `i`.
+/// The length of `input` must be a power of two.
+pub fn bit_reverse_permute<T>(input: &mut [T]) {
+    debug_assert!(input.len().is_power_of_two() || input.is_empty());
+    for i in 0..input.len() {
+        let bit_reversed_index = reverse_index(i, input.len() as u64);
+        if bit_reversed_index > i {
+            input.swap(i, bit_reversed_index);
+        }
+    }
+}
+
+/// Reverses the `log2(size)` first bits of `i`.
+pub fn reverse_index(i: usize, size: u64) -> usize {
+    if size <= 1 {
+        i
+    } else {
+        i.reverse_bits() >> (usize::BITS - size.trailing_zeros())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_reverse_permute_works() {
+        let mut input = [0, 1, 2, 3, 4, 5, 6, 7];
+        bit_reverse_permute(&mut input);
+        assert_eq!(input, [0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn bit_reverse_permute_is_an_involution() {
+        let original: Vec<usize> = (0..64).collect();
+        let mut input = original.clone();
+        bit_reverse_permute(&mut input);
+        assert_ne!(input, original);
+        bit_reverse_permute(&mut input);
+        assert_eq!(input, original);
+    }
+
+    #[test]
+    fn reverse_index_of_small_sizes() {
+        assert_eq!(reverse_index(0, 1), 0);
+        assert_eq!(reverse_index(1, 2), 1);
+        assert_eq!(reverse_index(1, 4), 2);
+        assert_eq!(reverse_index(6, 16), 6);
+        assert_eq!(reverse_index(3, 16), 12);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{
+    field::{element::FieldElement, traits::IsTwoAdicField},
+    polynomial::Polynomial,
+};
+
+use super::{errors::FFTError, helpers::log2};
+
+/// An evaluation domain `offset * H`, where `H` is the subgroup of roots of unity
+/// of order `size`. The elements of the domain are `offset * generator^i`
+/// for `i` in `0..size`.
+#[derive(Debug, Clone)]
+pub struct Domain<F: IsTwoAdicField> {
+    size: usize,
+    generator: FieldElement<F>,
+    offset: FieldElement<F>,
+}
+
+impl<F: IsTwoAdicField> Domain<F> {
+    /// Creates the subgroup of roots of unity of order `size`, which must be a power of two.
+    pub fn new(size: usize) -> Result<Self, FFTError> {
+        Self::new_coset(size, FieldElement::one())
+    }
+
+    /// Creates the coset `offset * H` of the subgroup `H` of roots of unity of order `size`.
+    /// `size` must be a power of two and `offset` must be nonzero.
+    pub fn new_coset(size: usize, offset: FieldElement<F>) -> Result<Self, FFTError> {
+        if offset == FieldElement::zero() {
+            return Err(FFTError::ZeroOffset);
+        }
+        let generator = match log2(size)? {
+            0 => FieldElement::one(),
+            order => F::get_root_of_unity(order)?,
+        };
+        Ok(Self {
+            size,
+            generator,
+            offset,
+        })
+    }
+
+    /// Returns the number of elements of the domain.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the generator of the subgroup `H`.
+    pub fn generator(&self) -> &FieldElement<F> {
+        &self.generator
+    }
+
+    /// Returns the offset of the coset.
+    pub fn offset(&self) -> &FieldElement<F> {
+        &self.offset
+    }
+
+    /// Returns an iterator over the elements `offset * generator^i` of the domain.
+    pub fn elements(&self) -> impl Iterator<Item = FieldElement<F>> + '_ {
+        (0..self.size).scan(self.offset.clone(), |element, _| {
+            let current = element.clone();
+            *element = &*element * &self.generator;
+            Some(current)
+        })
+    }
+
+    /// Returns the vanishing polynomial of the domain, `Z(X) = X^size - offset^size`.
+    pub fn vanishing_polynomial(&self) -> Polynomial<FieldElement<F>> {
+        Polynomial::new_monomial(FieldElement::one(), self.size)
+            - Polynomial::new(&[self.offset_pow_size()])
+    }
+
+    /// Evaluates the vanishing polynomial of the domain at `x`.
+    pub fn evaluate_vanishing_polynomial(&self, x: &FieldElement<F>) -> FieldElement<F> {
+        x.pow(self.size as u64) - self.offset_pow_size()
+    }
+
+    /// Returns the evaluations at `x` of the Lagrange basis polynomials of the domain,
+    /// `L_i(X) = Z(X) * h_i / (size * offset^size * (X - h_i))`, where `h_i` is the
+    /// `i`-th element of the domain.
+    pub fn lagrange_basis_evaluations(&self, x: &FieldElement<F>) -> Vec<FieldElement<F>> {
+        let Some((normalization_inverse, inverse_differences)) = self.inverse_denominators(x)
+        else {
+            return self
+                .elements()
+                .map(|h| {
+                    if &h == x {
+                        FieldElement::one()
+                    } else {
+                        FieldElement::zero()
+                    }
+                })
+                .collect();
+        };
+        let factor = self.evaluate_vanishing_polynomial(x) * normalization_inverse;
+        self.elements()
+            .zip(inverse_differences)
+            .map(|(h, inverse_difference)| &factor * h * inverse_difference)
+            .collect()
+    }
+
+    /// Evaluates at `x` the polynomial of degree less than `size` that takes the values
+    /// `evaluations` on the elements of the domain, with the barycentric formula
+    /// `p(x) = Z(x) / (size * offset^size) * sum_i evaluations[i] * h_i / (x - h_i)`.
+    /// Panics if `evaluations` does not have `size` elements.
+    pub fn barycentric_evaluation(
+        &self,
+        evaluations: &[FieldElement<F>],
+        x: &FieldElement<F>,
+    ) -> FieldElement<F> {
+        assert_eq!(
+            evaluations.len(),
+            self.size,
+            "The number of evaluations must match the size of the domain"
+        );
+        let Some((normalization_inverse, inverse_differences)) = self.inverse_denominators(x)
+        else {
+            let position = self
+                .elements()
+                .position(|h| &h == x)
+                .expect("only the elements of the domain have no inverse denominators");
+            return evaluations[position].clone();
+        };
+        let sum = self
+            .elements()
+            .zip(evaluations)
+            .zip(inverse_differences)
+            .fold(FieldElement::zero(), |sum, ((h, y), inverse_difference)| {
+                sum + y * h * inverse_difference
+            });
+        self.evaluate_vanishing_polynomial(x) * normalization_inverse * sum
+    }
+
+    fn offset_pow_size(&self) -> FieldElement<F> {
+        self.offset.pow(self.size as u64)
+    }
+
+    /// Returns `1 / (size * offset^size)` and the inverses of `x - h_i` for the elements
+    /// `h_i` of the domain, computed with a single inversion. Returns `None` if `x` is an
+    /// element of the domain, since the offset is nonzero and `size` is smaller than the
+    /// characteristic of a field with roots of unity of that order.
+    fn inverse_denominators(
+        &self,
+        x: &FieldElement<F>,
+    ) -> Option<(FieldElement<F>, Vec<FieldElement<F>>)> {
+        let mut denominators: Vec<_> = self.elements().map(|h| x - h).collect();
+        denominators.push(FieldElement::from(self.size as u64) * self.offset_pow_size());
+        FieldElement::inplace_batch_inverse(&mut denominators).ok()?;
+        let normalization_inverse = denominators.pop()?;
+        Some((normalization_inverse, denominators))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+
+    const MODULUS: u64 = 0xFFFFFFFF00000001;
+    type F = U64TestField<MODULUS>;
+    type FE = FieldElement<F>;
+
+    fn coset() -> Domain<F> {
+        Domain::new_coset(8, FE::new(F::GENERATOR)).unwrap()
+    }
+
+    #[test]
+    fn elements_of_the_subgroup_are_the_powers_of_the_generator() {
+        let domain = Domain::<F>::new(16).unwrap();
+        let omega = F::get_root_of_unity(4).unwrap();
+        let elements: Vec<FE> = domain.elements().collect();
+        assert_eq!(elements.len(), 16);
+        for (i, element) in elements.iter().enumerate() {
+            assert_eq!(*element, omega.pow(i as u64));
+        }
+        assert_eq!(domain.offset(), &FE::one());
+        assert_eq!(domain.generator(), &omega);
+    }
+
+    #[test]
+    fn domain_of_size_one_contains_the_offset() {
+        let domain = Domain::new_coset(1, FE::from(5)).unwrap();
+        assert_eq!(domain.elements().collect::<Vec<_>>(), vec![FE::from(5)]);
+    }
+
+    #[test]
+    fn domain_with_invalid_size_fails() {
+        assert!(matches!(
+            Domain::<F>::new(12),
+            Err(FFTError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn coset_with_zero_offset_fails() {
+        assert!(matches!(
+            Domain::new_coset(8, FE::zero()),
+            Err(FFTError::ZeroOffset)
+        ));
+    }
+
+    #[test]
+    fn vanishing_polynomial_vanishes_on_the_domain() {
+        let domain = coset();
+        let vanishing_polynomial = domain.vanishing_polynomial();
+        assert_eq!(vanishing_polynomial.degree(), 8);
+        for element in domain.elements() {
+            assert_eq!(vanishing_polynomial.evaluate(&element), FE::zero());
+        }
+        let x = FE::from(12345);
+        assert_eq!(
+            domain.evaluate_vanishing_polynomial(&x),
+            vanishing_polynomial.evaluate(&x)
+        );
+        assert_ne!(domain.evaluate_vanishing_polynomial(&x), FE::zero());
+    }
+
+    #[test]
+    fn lagrange_basis_evaluations_match_interpolation() {
+        let domain = coset();
+        let xs: Vec<FE> = domain.elements().collect();
+        let x = FE::from(777);
+        let evaluations = domain.lagrange_basis_evaluations(&x);
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            let mut ys = vec![FE::zero(); domain.size()];
+            ys[i] = FE::one();
+            let lagrange_polynomial = Polynomial::interpolate(&xs, &ys).unwrap();
+            assert_eq!(*evaluation, lagrange_polynomial.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn lagrange_basis_evaluations_on_the_domain_are_the_indicator() {
+        let domain = coset();
+        let x = domain.elements().nth(3).unwrap();
+        let evaluations = domain.lagrange_basis_evaluations(&x);
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            let expected = if i == 3 { FE::one() } else { FE::zero() };
+            assert_eq!(*evaluation, expected);
+        }
+    }
+
+    #[test]
+    fn barycentric_evaluation_matches_polynomial_evaluation() {
+        let domain = coset();
+        let poly = Polynomial::new(&[FE::from(2), FE::from(7), FE::from(1), FE::from(8)]);
+        let evaluations: Vec<FE> = domain.elements().map(|h| poly.evaluate(&h)).collect();
+        for x in [
+            FE::from(100),
+            FE::from(3),
+            domain.elements().nth(5).unwrap(),
+        ] {
+            assert_eq!(
+                domain.barycentric_evaluation(&evaluations, &x),
+                poly.evaluate(&x)
+            );
+        }
+    }
+}
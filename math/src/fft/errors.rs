@@ -6,4 +6,6 @@ pub enum FFTError {
     InvalidOrder(String),
     #[error("Could not calculate {1} root of unity")]
     RootOfUnityError(String, u64),
+    #[error("The offset of a coset must be nonzero")]
+    ZeroOffset,
 }
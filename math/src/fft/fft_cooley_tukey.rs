@@ -3,13 +3,27 @@ use crate::field::{
     traits::{IsField, IsTwoAdicField},
 };
 
-use super::{errors::FFTError, helpers::log2};
+use super::{
+    bit_reversing::bit_reverse_permute,
+    errors::FFTError,
+    helpers::log2,
+    ntt::{
+        compute_twiddles, get_twiddles, in_place_dif_ntt, in_place_ntt, NttOrdering, RootsConfig,
+    },
+};
 
 pub fn fft<F: IsField + IsTwoAdicField>(
     coeffs: &[FieldElement<F>],
 ) -> Result<Vec<FieldElement<F>>, FFTError> {
-    let omega = F::get_root_of_unity(log2(coeffs.len())?)?;
-    Ok(cooley_tukey(coeffs, &omega))
+    let twiddles = get_twiddles(log2(coeffs.len())?, RootsConfig::Natural)?;
+    let mut evaluations = coeffs.to_vec();
+    in_place_ntt(
+        &mut evaluations,
+        &twiddles,
+        NttOrdering::Natural,
+        NttOrdering::Natural,
+    )?;
+    Ok(evaluations)
 }
 
 pub fn inverse_fft<F: IsField + IsTwoAdicField>(
@@ -19,42 +33,57 @@ pub fn inverse_fft<F: IsField + IsTwoAdicField>(
     Ok(inverse_cooley_tukey(evaluations, omega))
 }
 
-fn cooley_tukey<F: IsField>(
+/// Evaluates the polynomial with coefficients `coeffs` on the coset `offset * H`,
+/// where `H` is the subgroup of roots of unity of order `coeffs.len()`.
+/// The `i`-th output is the evaluation at `offset * omega^i`.
+pub fn fft_coset<F: IsField + IsTwoAdicField>(
     coeffs: &[FieldElement<F>],
-    omega: &FieldElement<F>,
-) -> Vec<FieldElement<F>> {
-    let n = coeffs.len();
-    if n == 1 {
-        return coeffs.to_vec();
-    }
-    let coeffs_even: Vec<FieldElement<F>> = coeffs.iter().step_by(2).cloned().collect();
-    let coeffs_odd: Vec<FieldElement<F>> = coeffs.iter().skip(1).step_by(2).cloned().collect();
-
-    let (y_even, y_odd) = (
-        cooley_tukey(&coeffs_even, omega),
-        cooley_tukey(&coeffs_odd, omega),
-    );
-    let mut y = vec![FieldElement::zero(); n];
-    for i in 0..n / 2 {
-        let a = &y_even[i];
-        let b = &(omega.pow(i) * &y_odd[i]);
-        y[i] = a + b;
-        y[i + n / 2] = a - b;
-    }
-    y
+    offset: &FieldElement<F>,
+) -> Result<Vec<FieldElement<F>>, FFTError> {
+    // p(offset * x) has coefficients c_i * offset^i.
+    let mut power = FieldElement::one();
+    let scaled_coeffs: Vec<FieldElement<F>> = coeffs
+        .iter()
+        .map(|coeff| {
+            let scaled = coeff * &power;
+            power = &power * offset;
+            scaled
+        })
+        .collect();
+    fft(&scaled_coeffs)
+}
+
+/// Interpolates the polynomial whose evaluations at `offset * omega^i` are `evaluations`.
+/// This is the inverse of `fft_coset`.
+pub fn inverse_fft_coset<F: IsField + IsTwoAdicField>(
+    evaluations: &[FieldElement<F>],
+    offset: &FieldElement<F>,
+) -> Result<Vec<FieldElement<F>>, FFTError> {
+    let offset_inv = offset.inv();
+    let mut power = FieldElement::one();
+    Ok(inverse_fft(evaluations)?
+        .iter()
+        .map(|coeff| {
+            let scaled = coeff * &power;
+            power = &power * &offset_inv;
+            scaled
+        })
+        .collect())
 }
 
+/// Interpolates the polynomial whose evaluations at the powers of `omega` are `evaluations`.
+/// `omega` must be a primitive root of unity of order `evaluations.len()`, which must be a power of two.
 pub fn inverse_cooley_tukey<F: IsField>(
     evaluations: &[FieldElement<F>],
     omega: FieldElement<F>,
 ) -> Vec<FieldElement<F>> {
     let n = evaluations.len();
     let inverse_n = FieldElement::from(n as u64).inv();
-    let inverse_omega = omega.inv();
-    cooley_tukey(evaluations, &inverse_omega)
-        .iter()
-        .map(|coeff| coeff * &inverse_n)
-        .collect()
+    let twiddles = compute_twiddles(&omega.inv(), n);
+    let mut coeffs = evaluations.to_vec();
+    in_place_dif_ntt(&mut coeffs, &twiddles);
+    bit_reverse_permute(&mut coeffs);
+    coeffs.iter().map(|coeff| coeff * &inverse_n).collect()
 }
 
 #[cfg(test)]
@@ -115,6 +144,34 @@ mod fft_test {
         }
     }
 
+    proptest! {
+        // Property-based test that ensures the coset FFT evaluates on the shifted domain.
+        #[test]
+        fn test_fft_coset_matches_naive_evaluation(coeffs in field_vec(8), offset in field_element()) {
+            prop_assume!(offset != FE::zero());
+            let poly = Polynomial::new(&coeffs[..]);
+            let omega = F::get_root_of_unity(log2(coeffs.len()).unwrap()).unwrap();
+
+            let result = fft_coset(&coeffs, &offset).unwrap();
+
+            let expected: Vec<FE> = (0..coeffs.len() as u64)
+                .map(|i| poly.evaluate(&(&offset * omega.pow(i))))
+                .collect();
+            prop_assert_eq!(result, expected);
+        }
+    }
+    proptest! {
+        // Property-based test that ensures the inverse coset FFT is the inverse of the coset FFT.
+        #[test]
+        fn test_inverse_fft_coset_composed_fft_coset_is_identity(coeffs in field_vec(8), offset in field_element()) {
+            prop_assume!(offset != FE::zero());
+            let result = fft_coset(&coeffs, &offset).unwrap();
+            let recovered_poly = inverse_fft_coset(&result, &offset).unwrap();
+
+            prop_assert_eq!(recovered_poly, coeffs);
+        }
+    }
+
     proptest! {
         // Property-based test that ensures FFT won't work with a non-power-of-two polynomial.
         #[test]
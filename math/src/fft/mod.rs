@@ -1,3 +1,7 @@
+pub mod bit_reversing;
+pub mod domain;
 pub mod errors;
 pub mod fft_cooley_tukey;
 mod helpers;
+pub mod ntt;
+pub mod polynomial;
@@ -0,0 +1,269 @@
+use crate::field::{
+    element::FieldElement,
+    traits::{IsField, IsTwoAdicField},
+};
+
+use super::{bit_reversing::bit_reverse_permute, errors::FFTError, helpers::log2};
+
+/// Order of the elements of the input or the output of an NTT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NttOrdering {
+    /// The element at index `i` corresponds to the `i`-th coefficient or evaluation.
+    Natural,
+    /// The element at index `i` corresponds to the coefficient or evaluation whose
+    /// index is obtained by reversing the bits of `i`.
+    BitReversed,
+}
+
+/// Selects which root of unity is used to build a twiddle table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootsConfig {
+    /// Powers of the primitive root of unity, used for the NTT.
+    Natural,
+    /// Powers of the inverse of the primitive root of unity, used for the inverse NTT.
+    NaturalInversed,
+}
+
+/// Returns the twiddle factors `[1, omega, ..., omega^(n / 2 - 1)]` needed by an NTT of size `n`.
+pub fn compute_twiddles<F: IsField>(omega: &FieldElement<F>, n: usize) -> Vec<FieldElement<F>> {
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut power = FieldElement::one();
+    for _ in 0..n / 2 {
+        twiddles.push(power.clone());
+        power = power * omega;
+    }
+    twiddles
+}
+
+/// Returns the twiddle factors for an NTT of size `2^order`, using the primitive
+/// root of unity of that order or its inverse, as selected by `config`.
+pub fn get_twiddles<F: IsTwoAdicField>(
+    order: u64,
+    config: RootsConfig,
+) -> Result<Vec<FieldElement<F>>, FFTError> {
+    let omega = F::get_root_of_unity(order)?;
+    let omega = match config {
+        RootsConfig::Natural => omega,
+        RootsConfig::NaturalInversed => omega.inv(),
+    };
+    Ok(compute_twiddles(&omega, 1 << order))
+}
+
+/// Decimation-in-time radix-2 NTT (Cooley-Tukey butterflies).
+/// Takes the input in bit-reversed order and leaves the output in natural order.
+/// `twiddles` must contain exactly the first `input.len() / 2` powers of a primitive
+/// root of unity of order `input.len()`, whose length must be a power of two.
+pub fn in_place_dit_ntt<F: IsField>(input: &mut [FieldElement<F>], twiddles: &[FieldElement<F>]) {
+    let n = input.len();
+    let mut block_size = 2;
+    while block_size <= n {
+        let half = block_size / 2;
+        let stride = n / block_size;
+        for start in (0..n).step_by(block_size) {
+            for j in 0..half {
+                let v = &input[start + j + half] * &twiddles[j * stride];
+                let u = input[start + j].clone();
+                input[start + j] = &u + &v;
+                input[start + j + half] = u - v;
+            }
+        }
+        block_size *= 2;
+    }
+}
+
+/// Decimation-in-frequency radix-2 NTT (Gentleman-Sande butterflies).
+/// Takes the input in natural order and leaves the output in bit-reversed order.
+/// `twiddles` must contain exactly the first `input.len() / 2` powers of a primitive
+/// root of unity of order `input.len()`, whose length must be a power of two.
+pub fn in_place_dif_ntt<F: IsField>(input: &mut [FieldElement<F>], twiddles: &[FieldElement<F>]) {
+    let n = input.len();
+    let mut block_size = n;
+    while block_size >= 2 {
+        let half = block_size / 2;
+        let stride = n / block_size;
+        for start in (0..n).step_by(block_size) {
+            for j in 0..half {
+                let u = input[start + j].clone();
+                let v = input[start + j + half].clone();
+                input[start + j] = &u + &v;
+                input[start + j + half] = (u - v) * &twiddles[j * stride];
+            }
+        }
+        block_size /= 2;
+    }
+}
+
+/// Computes in place the NTT of `input` with the given twiddle factors, reading the
+/// input in `input_ordering` and leaving the output in `output_ordering`.
+/// The twiddle table must be the one of a domain of size `input.len()`: a table of a
+/// larger domain holds powers of a different root of unity, so it is rejected.
+/// The DIF variant is used for inputs in natural order and the DIT one otherwise,
+/// so a bit-reversal permutation is only performed when both orderings are equal.
+pub fn in_place_ntt<F: IsField>(
+    input: &mut [FieldElement<F>],
+    twiddles: &[FieldElement<F>],
+    input_ordering: NttOrdering,
+    output_ordering: NttOrdering,
+) -> Result<(), FFTError> {
+    log2(input.len())?;
+    if twiddles.len() != input.len() / 2 {
+        return Err(FFTError::InvalidOrder(
+            "The twiddle table must have half the length of the input".to_string(),
+        ));
+    }
+    match input_ordering {
+        NttOrdering::Natural => {
+            in_place_dif_ntt(input, twiddles);
+            if output_ordering == NttOrdering::Natural {
+                bit_reverse_permute(input);
+            }
+        }
+        NttOrdering::BitReversed => {
+            in_place_dit_ntt(input, twiddles);
+            if output_ordering == NttOrdering::BitReversed {
+                bit_reverse_permute(input);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+    use crate::polynomial::Polynomial;
+
+    const MODULUS: u64 = 0xFFFFFFFF00000001;
+    type F = U64TestField<MODULUS>;
+    type FE = FieldElement<F>;
+
+    fn coefficients(n: u64) -> Vec<FE> {
+        (0..n).map(|i| FE::from(i * i + 7 * i + 3)).collect()
+    }
+
+    fn naive_evaluations(coefficients: &[FE]) -> Vec<FE> {
+        let order = log2(coefficients.len()).unwrap();
+        let omega = F::get_root_of_unity(order).unwrap();
+        let poly = Polynomial::new(coefficients);
+        (0..coefficients.len() as u64)
+            .map(|i| poly.evaluate(&omega.pow(i)))
+            .collect()
+    }
+
+    #[test]
+    fn dit_with_bit_reversed_input_matches_naive_evaluation() {
+        let coefficients = coefficients(16);
+        let twiddles = get_twiddles::<F>(4, RootsConfig::Natural).unwrap();
+        let mut input = coefficients.clone();
+        bit_reverse_permute(&mut input);
+        in_place_dit_ntt(&mut input, &twiddles);
+        assert_eq!(input, naive_evaluations(&coefficients));
+    }
+
+    #[test]
+    fn dif_leaves_the_output_in_bit_reversed_order() {
+        let coefficients = coefficients(16);
+        let twiddles = get_twiddles::<F>(4, RootsConfig::Natural).unwrap();
+        let mut input = coefficients.clone();
+        in_place_dif_ntt(&mut input, &twiddles);
+        bit_reverse_permute(&mut input);
+        assert_eq!(input, naive_evaluations(&coefficients));
+    }
+
+    #[test]
+    fn every_ordering_combination_matches_naive_evaluation() {
+        use NttOrdering::*;
+        let coefficients = coefficients(32);
+        let twiddles = get_twiddles::<F>(5, RootsConfig::Natural).unwrap();
+        let expected = naive_evaluations(&coefficients);
+        let mut expected_bit_reversed = expected.clone();
+        bit_reverse_permute(&mut expected_bit_reversed);
+        let mut coefficients_bit_reversed = coefficients.clone();
+        bit_reverse_permute(&mut coefficients_bit_reversed);
+
+        for (input_ordering, output_ordering) in [
+            (Natural, Natural),
+            (Natural, BitReversed),
+            (BitReversed, Natural),
+            (BitReversed, BitReversed),
+        ] {
+            let mut input = match input_ordering {
+                Natural => coefficients.clone(),
+                BitReversed => coefficients_bit_reversed.clone(),
+            };
+            in_place_ntt(&mut input, &twiddles, input_ordering, output_ordering).unwrap();
+            match output_ordering {
+                Natural => assert_eq!(input, expected),
+                BitReversed => assert_eq!(input, expected_bit_reversed),
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_twiddles_invert_the_ntt() {
+        let coefficients = coefficients(8);
+        let twiddles = get_twiddles::<F>(3, RootsConfig::Natural).unwrap();
+        let inverse_twiddles = get_twiddles::<F>(3, RootsConfig::NaturalInversed).unwrap();
+        let mut input = coefficients.clone();
+        in_place_ntt(
+            &mut input,
+            &twiddles,
+            NttOrdering::Natural,
+            NttOrdering::BitReversed,
+        )
+        .unwrap();
+        in_place_ntt(
+            &mut input,
+            &inverse_twiddles,
+            NttOrdering::BitReversed,
+            NttOrdering::Natural,
+        )
+        .unwrap();
+        let inverse_n = FE::from(8).inv();
+        let recovered: Vec<FE> = input.iter().map(|x| x * &inverse_n).collect();
+        assert_eq!(recovered, coefficients);
+    }
+
+    #[test]
+    fn ntt_of_invalid_sizes_fails() {
+        let twiddles = get_twiddles::<F>(3, RootsConfig::Natural).unwrap();
+        let mut input = coefficients(6);
+        assert!(matches!(
+            in_place_ntt(
+                &mut input,
+                &twiddles,
+                NttOrdering::Natural,
+                NttOrdering::Natural
+            ),
+            Err(FFTError::InvalidOrder(_))
+        ));
+        let mut input = coefficients(16);
+        assert!(matches!(
+            in_place_ntt(
+                &mut input,
+                &twiddles,
+                NttOrdering::Natural,
+                NttOrdering::Natural
+            ),
+            Err(FFTError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn ntt_with_twiddles_of_another_size_fails() {
+        let mut input = coefficients(8);
+        for order in [2, 4] {
+            let twiddles = get_twiddles::<F>(order, RootsConfig::Natural).unwrap();
+            assert!(matches!(
+                in_place_ntt(
+                    &mut input,
+                    &twiddles,
+                    NttOrdering::Natural,
+                    NttOrdering::Natural
+                ),
+                Err(FFTError::InvalidOrder(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{
+    field::{element::FieldElement, traits::IsTwoAdicField},
+    polynomial::Polynomial,
+};
+
+use super::{
+    errors::FFTError,
+    fft_cooley_tukey::{fft, fft_coset, inverse_fft, inverse_fft_coset},
+};
+
+/// Number of coefficients of the smallest factor from which `fast_mul`
+/// multiplies polynomials with FFTs instead of the schoolbook method.
+const FFT_MULTIPLICATION_THRESHOLD: usize = 64;
+
+impl<F: IsTwoAdicField> Polynomial<FieldElement<F>> {
+    /// Evaluates the polynomial on the coset `offset * H`, where `H` is the subgroup of
+    /// roots of unity of order `blowup_factor` times the number of coefficients rounded up
+    /// to a power of two. This is the low-degree extension of the polynomial.
+    /// If `offset` is `None`, the generator `F::GENERATOR` is used as the shift.
+    /// `blowup_factor` must be a power of two.
+    pub fn evaluate_offset_fft(
+        &self,
+        blowup_factor: usize,
+        offset: Option<&FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, FFTError> {
+        let domain_size = self.coefficients().len().next_power_of_two() * blowup_factor;
+        let mut coeffs = self.coefficients().to_vec();
+        coeffs.resize(domain_size, FieldElement::zero());
+        match offset {
+            Some(offset) => fft_coset(&coeffs, offset),
+            None => fft_coset(&coeffs, &FieldElement::new(F::GENERATOR)),
+        }
+    }
+
+    /// Returns the polynomial whose evaluations on the coset `offset * H` are `evaluations`,
+    /// where `H` is the subgroup of roots of unity of order `evaluations.len()`.
+    /// If `offset` is `None`, the generator `F::GENERATOR` is used as the shift.
+    pub fn interpolate_offset_fft(
+        evaluations: &[FieldElement<F>],
+        offset: Option<&FieldElement<F>>,
+    ) -> Result<Self, FFTError> {
+        let coeffs = match offset {
+            Some(offset) => inverse_fft_coset(evaluations, offset)?,
+            None => inverse_fft_coset(evaluations, &FieldElement::new(F::GENERATOR))?,
+        };
+        Ok(Polynomial::new(&coeffs))
+    }
+
+    /// Multiplies `self` and `factor` by evaluating both on the roots of unity of order
+    /// the number of coefficients of the product rounded up to a power of two.
+    pub fn mul_fft(&self, factor: &Self) -> Result<Self, FFTError> {
+        if self.coefficients().is_empty() || factor.coefficients().is_empty() {
+            return Ok(Polynomial::zero());
+        }
+        let result_len = self.coefficients().len() + factor.coefficients().len() - 1;
+        let domain_size = result_len.next_power_of_two();
+        let mut lhs = self.coefficients().to_vec();
+        let mut rhs = factor.coefficients().to_vec();
+        lhs.resize(domain_size, FieldElement::zero());
+        rhs.resize(domain_size, FieldElement::zero());
+        let evaluations: Vec<FieldElement<F>> = fft(&lhs)?
+            .iter()
+            .zip(fft(&rhs)?)
+            .map(|(a, b)| a * b)
+            .collect();
+        let coeffs = inverse_fft(&evaluations)?;
+        Ok(Polynomial::new(&coeffs[..result_len]))
+    }
+
+    /// Multiplies `self` and `factor`, with FFTs if both have at least
+    /// `FFT_MULTIPLICATION_THRESHOLD` coefficients and with the schoolbook method otherwise.
+    /// It also falls back to the schoolbook method if the field has no roots of unity
+    /// of the needed order.
+    /// The `Mul` operator does not dispatch here, since it is implemented for every field,
+    /// so callers multiplying large polynomials must call this method instead.
+    pub fn fast_mul(&self, factor: &Self) -> Self {
+        let min_len = self.coefficients().len().min(factor.coefficients().len());
+        if min_len < FFT_MULTIPLICATION_THRESHOLD {
+            return self.mul_with_ref(factor);
+        }
+        self.mul_fft(factor)
+            .unwrap_or_else(|_| self.mul_with_ref(factor))
+    }
+
+    /// Computes quotient and remainder of polynomial division, computing the reversed
+    /// quotient as the reversed dividend times the inverse of the reversed divisor modulo
+    /// `X^(deg(self) - deg(divisor) + 1)`. The inverse is computed with Newton iteration.
+    /// Panics if `divisor` is the zero polynomial.
+    ///
+    /// Output: (quotient, remainder)
+    pub fn fast_division_with_remainder(&self, divisor: &Self) -> (Self, Self) {
+        let n = self.coefficients().len();
+        let m = divisor.coefficients().len();
+        assert!(m > 0, "Cannot divide by the zero polynomial");
+        if n < m {
+            return (Polynomial::zero(), self.clone());
+        }
+        let quotient_len = n - m + 1;
+        let reversed_dividend = reverse(self.coefficients());
+        let reversed_divisor = reverse(divisor.coefficients());
+        let inverse = reversed_divisor.inverse_mod_x_pow(quotient_len);
+        let mut quotient = truncate(&reversed_dividend.fast_mul(&inverse), quotient_len)
+            .coefficients()
+            .to_vec();
+        quotient.resize(quotient_len, FieldElement::zero());
+        quotient.reverse();
+        let quotient = Polynomial::new(&quotient);
+        let remainder = self.clone() - quotient.fast_mul(divisor);
+        (quotient, remainder)
+    }
+
+    /// Returns `g` such that `self * g = 1 mod X^k`, by Newton iteration
+    /// `g <- g * (2 - self * g)`, which doubles the precision at each step.
+    /// The constant coefficient of `self` must be nonzero.
+    fn inverse_mod_x_pow(&self, k: usize) -> Self {
+        let mut inverse = Polynomial::new(&[self.coefficients()[0].inv()]);
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            let error = truncate(&truncate(self, precision).fast_mul(&inverse), precision);
+            let correction = Polynomial::new(&[FieldElement::from(2)]) - error;
+            inverse = truncate(&inverse.fast_mul(&correction), precision);
+        }
+        inverse
+    }
+}
+
+/// Returns the polynomial with the coefficients of `coefficients` in reverse order.
+fn reverse<F: IsTwoAdicField>(coefficients: &[FieldElement<F>]) -> Polynomial<FieldElement<F>> {
+    let mut coefficients = coefficients.to_vec();
+    coefficients.reverse();
+    Polynomial::new(&coefficients)
+}
+
+/// Returns `p mod X^k`.
+fn truncate<F: IsTwoAdicField>(
+    p: &Polynomial<FieldElement<F>>,
+    k: usize,
+) -> Polynomial<FieldElement<F>> {
+    let len = p.coefficients().len().min(k);
+    Polynomial::new(&p.coefficients()[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::test_fields::u64_test_field::U64TestField;
+
+    const MODULUS: u64 = 0xFFFFFFFF00000001;
+    type F = U64TestField<MODULUS>;
+    type FE = FieldElement<F>;
+
+    fn polynomial() -> Polynomial<FE> {
+        Polynomial::new(&[
+            FE::from(3),
+            FE::from(1),
+            FE::from(4),
+            FE::from(1),
+            FE::from(5),
+        ])
+    }
+
+    #[test]
+    fn evaluate_offset_fft_evaluates_on_the_shifted_blown_up_domain() {
+        let poly = polynomial();
+        let offset = FE::from(3);
+        let evaluations = poly.evaluate_offset_fft(4, Some(&offset)).unwrap();
+        // 5 coefficients are padded to 8 and blown up to 32 evaluations.
+        assert_eq!(evaluations.len(), 32);
+        let omega = F::get_root_of_unity(5).unwrap();
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            assert_eq!(*evaluation, poly.evaluate(&(&offset * omega.pow(i as u64))));
+        }
+    }
+
+    #[test]
+    fn evaluate_offset_fft_uses_the_generator_by_default() {
+        let poly = polynomial();
+        let generator = FE::new(F::GENERATOR);
+        assert_eq!(
+            poly.evaluate_offset_fft(2, None).unwrap(),
+            poly.evaluate_offset_fft(2, Some(&generator)).unwrap()
+        );
+        assert_eq!(
+            poly.evaluate_offset_fft(2, None).unwrap()[0],
+            poly.evaluate(&generator)
+        );
+    }
+
+    #[test]
+    fn interpolate_offset_fft_recovers_the_polynomial() {
+        let poly = polynomial();
+        let evaluations = poly.evaluate_offset_fft(2, None).unwrap();
+        assert_eq!(
+            Polynomial::interpolate_offset_fft(&evaluations, None).unwrap(),
+            poly
+        );
+    }
+
+    fn polynomial_with_len(len: u64, seed: u64) -> Polynomial<FE> {
+        let coeffs: Vec<FE> = (0..len)
+            .map(|i| FE::from(i * i * seed + 3 * i + seed))
+            .collect();
+        Polynomial::new(&coeffs)
+    }
+
+    #[test]
+    fn mul_fft_matches_schoolbook_multiplication() {
+        let a = polynomial_with_len(13, 7);
+        let b = polynomial_with_len(6, 11);
+        assert_eq!(a.mul_fft(&b).unwrap(), a.mul_with_ref(&b));
+        assert_eq!(a.mul_fft(&Polynomial::zero()).unwrap(), Polynomial::zero());
+    }
+
+    #[test]
+    fn fast_mul_above_the_threshold_matches_schoolbook_multiplication() {
+        let a = polynomial_with_len(150, 5);
+        let b = polynomial_with_len(70, 9);
+        assert_eq!(a.fast_mul(&b), a.mul_with_ref(&b));
+    }
+
+    #[test]
+    fn inverse_mod_x_pow_is_an_inverse() {
+        let a = polynomial_with_len(40, 3);
+        let inverse = a.inverse_mod_x_pow(37);
+        assert_eq!(
+            truncate(&a.mul_with_ref(&inverse), 37),
+            Polynomial::new(&[FE::one()])
+        );
+    }
+
+    #[test]
+    fn fast_division_matches_long_division() {
+        let a = polynomial_with_len(200, 13);
+        let b = polynomial_with_len(71, 2);
+        let (quotient, remainder) = a.fast_division_with_remainder(&b);
+        assert_eq!((quotient, remainder), a.long_division_with_remainder(&b));
+    }
+
+    #[test]
+    fn fast_division_of_a_multiple_has_zero_remainder() {
+        let a = polynomial_with_len(90, 4);
+        let b = polynomial_with_len(80, 6);
+        let (quotient, remainder) = a.fast_mul(&b).fast_division_with_remainder(&b);
+        assert_eq!(quotient, a);
+        assert_eq!(remainder, Polynomial::zero());
+    }
+
+    #[test]
+    fn fast_division_by_a_larger_polynomial_returns_the_dividend_as_remainder() {
+        let a = polynomial_with_len(5, 4);
+        let b = polynomial_with_len(8, 6);
+        assert_eq!(
+            a.fast_division_with_remainder(&b),
+            (Polynomial::zero(), a.clone())
+        );
+    }
+
+    #[test]
+    fn evaluate_offset_fft_with_invalid_blowup_factor_fails() {
+        assert!(matches!(
+            polynomial().evaluate_offset_fft(3, None),
+            Err(FFTError::InvalidOrder(_))
+        ));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::field::traits::IsField;
+#[cfg(feature = "constant-time")]
+use crate::constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::field::errors::FieldError;
+use crate::field::traits::{IsField, IsPrimeField, LegendreSymbol};
 use crate::unsigned_integer::traits::IsUnsignedInteger;
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
@@ -269,6 +272,32 @@ where
     }
 }
 
+#[cfg(feature = "constant-time")]
+impl<F> ConditionallySelectable for FieldElement<F>
+where
+    F: IsField,
+    F::BaseType: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            value: F::BaseType::conditional_select(&a.value, &b.value, choice),
+        }
+    }
+}
+
+/// Compares the internal representations, so it requires them to be unique,
+/// as in the Montgomery backed fields and their extensions.
+#[cfg(feature = "constant-time")]
+impl<F> ConstantTimeEq for FieldElement<F>
+where
+    F: IsField,
+    F::BaseType: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.value.ct_eq(&other.value)
+    }
+}
+
 /// FieldElement general implementation
 /// Most of this is delegated to the trait `F` that
 /// implements the field operations.
@@ -295,6 +324,44 @@ where
         }
     }
 
+    /// Returns the multiplicative inverse of `self`, or an error if `self` is zero.
+    pub fn try_inv(&self) -> Result<Self, FieldError> {
+        Ok(Self {
+            value: F::try_inv(&self.value)?,
+        })
+    }
+
+    /// Returns the division of `self` by `divisor`, or an error if `divisor` is zero.
+    pub fn try_div(&self, divisor: &Self) -> Result<Self, FieldError> {
+        Ok(self * divisor.try_inv()?)
+    }
+
+    /// Replaces every element of `numbers` by its multiplicative inverse.
+    /// Uses Montgomery's trick, so that it takes a single inversion and
+    /// `3 * (n - 1)` multiplications for `n` elements.
+    /// Returns an error, leaving `numbers` unchanged, if any of them is zero.
+    pub fn inplace_batch_inverse(numbers: &mut [Self]) -> Result<(), FieldError> {
+        // `prefix_products[i]` is the product of the elements before `numbers[i]`.
+        let mut prefix_products = Vec::with_capacity(numbers.len());
+        let mut product = Self::one();
+        for number in numbers.iter() {
+            prefix_products.push(product.clone());
+            product = &product * number;
+        }
+        if product == Self::zero() {
+            return Err(FieldError::DivisionByZero);
+        }
+
+        // `inverse` is the inverse of the product of the elements up to `numbers[i]`.
+        let mut inverse = product.inv();
+        for (number, prefix_product) in numbers.iter_mut().zip(prefix_products).rev() {
+            let number_inverse = &inverse * prefix_product;
+            inverse = &inverse * &*number;
+            *number = number_inverse;
+        }
+        Ok(())
+    }
+
     /// Returns `self` raised to the power of `exponent`
     pub fn pow<T>(&self, exponent: T) -> Self
     where
@@ -315,3 +382,83 @@ where
         Self { value: F::zero() }
     }
 }
+
+impl<F> FieldElement<F>
+where
+    F: IsPrimeField,
+{
+    /// Returns the canonical representative of `self`, that is,
+    /// the integer in the range `[0, p)` congruent to it.
+    pub fn representative(&self) -> F::RepresentativeType {
+        F::representative(&self.value)
+    }
+
+    /// Returns the Legendre symbol of `self`.
+    pub fn legendre_symbol(&self) -> LegendreSymbol {
+        F::legendre_symbol(&self.value)
+    }
+
+    /// Returns the two square roots of `self`, or `None` if it is not a square.
+    pub fn sqrt(&self) -> Option<(Self, Self)> {
+        let (root, negated_root) = F::sqrt(&self.value)?;
+        Some((
+            Self { value: root },
+            Self {
+                value: negated_root,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::errors::FieldError;
+    use crate::field::fields::u64_prime_field::U64FieldElement;
+
+    type FE = U64FieldElement<13>;
+
+    #[test]
+    fn try_inv_of_zero_is_an_error() {
+        assert_eq!(FE::zero().try_inv(), Err(FieldError::DivisionByZero));
+        assert_eq!(FE::from(13).try_inv(), Err(FieldError::DivisionByZero));
+    }
+
+    #[test]
+    fn try_inv_of_nonzero_element_is_its_inverse() {
+        assert_eq!(FE::from(2).try_inv(), Ok(FE::from(7)));
+    }
+
+    #[test]
+    fn try_div_by_zero_is_an_error() {
+        assert_eq!(
+            FE::from(3).try_div(&FE::zero()),
+            Err(FieldError::DivisionByZero)
+        );
+        assert_eq!(FE::from(3).try_div(&FE::from(2)), Ok(FE::from(8)));
+    }
+
+    #[test]
+    fn inplace_batch_inverse_inverts_every_element() {
+        let mut numbers: Vec<FE> = (1..13).map(FE::from).collect();
+        FE::inplace_batch_inverse(&mut numbers).unwrap();
+        for (i, inverse) in (1..13).zip(numbers) {
+            assert_eq!(FE::from(i) * inverse, FE::one());
+        }
+    }
+
+    #[test]
+    fn inplace_batch_inverse_of_empty_slice_works() {
+        let mut numbers: Vec<FE> = Vec::new();
+        assert!(FE::inplace_batch_inverse(&mut numbers).is_ok());
+    }
+
+    #[test]
+    fn inplace_batch_inverse_with_a_zero_fails_and_leaves_elements_unchanged() {
+        let mut numbers = vec![FE::from(3), FE::zero(), FE::from(5)];
+        assert_eq!(
+            FE::inplace_batch_inverse(&mut numbers),
+            Err(FieldError::DivisionByZero)
+        );
+        assert_eq!(numbers, vec![FE::from(3), FE::zero(), FE::from(5)]);
+    }
+}
@@ -1,7 +1,8 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum FieldError {
+    /// Zero was inverted, either directly or as a divisor.
     #[error("Can't divide by zero")]
-    DivisionByZero
+    DivisionByZero,
 }
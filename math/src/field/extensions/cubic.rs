@@ -1,5 +1,9 @@
+#[cfg(feature = "constant-time")]
+use crate::constant_time::IsConstantTimeField;
 use crate::field::element::FieldElement;
 use crate::field::traits::IsField;
+use crate::traits::RandomSample;
+use rand::Rng;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -129,6 +133,29 @@ where
     }
 }
 
+#[cfg(feature = "constant-time")]
+impl<Q> IsConstantTimeField for CubicExtensionField<Q>
+where
+    Q: Clone + Debug + HasCubicNonResidue,
+    Q::BaseField: IsConstantTimeField,
+{
+}
+
+/// Samples every component independently and uniformly at random.
+impl<Q> RandomSample for FieldElement<CubicExtensionField<Q>>
+where
+    Q: Clone + Debug + HasCubicNonResidue,
+    FieldElement<Q::BaseField>: RandomSample,
+{
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new([
+            FieldElement::<Q::BaseField>::random(rng),
+            FieldElement::<Q::BaseField>::random(rng),
+            FieldElement::<Q::BaseField>::random(rng),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::field::fields::u64_prime_field::{U64FieldElement, U64PrimeField};
@@ -1,5 +1,9 @@
+#[cfg(feature = "constant-time")]
+use crate::constant_time::IsConstantTimeField;
 use crate::field::element::FieldElement;
-use crate::field::traits::IsField;
+use crate::field::traits::{IsField, IsPrimeField, LegendreSymbol};
+use crate::traits::RandomSample;
+use rand::Rng;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -107,6 +111,66 @@ where
     }
 }
 
+impl<Q> QuadraticExtensionFieldElement<Q>
+where
+    Q: Clone + Debug + HasQuadraticNonResidue,
+    Q::BaseField: IsPrimeField,
+{
+    /// Returns the two square roots of `self`, or `None` if it is not a square.
+    /// Uses the complex method: for `a = a0 + a1 * t`, a square root `x0 + x1 * t`
+    /// satisfies `x0^2 = (a0 +- sqrt(a0^2 - a1^2 * Q::residue())) / 2` and `x1 = a1 / (2 * x0)`.
+    pub fn sqrt(&self) -> Option<(Self, Self)> {
+        let [a0, a1] = self.value();
+        let two_inv = FieldElement::<Q::BaseField>::from(2).inv();
+
+        let (x0, x1) = if *a1 == FieldElement::zero() {
+            // `a0` is either a square in the base field or `Q::residue()` times a square.
+            match a0.sqrt() {
+                Some((x0, _)) => (x0, FieldElement::zero()),
+                None => {
+                    let (x1, _) = (a0 / Q::residue()).sqrt()?;
+                    (FieldElement::zero(), x1)
+                }
+            }
+        } else {
+            let (norm_root, _) = (a0.pow(2_u16) - Q::residue() * a1.pow(2_u16)).sqrt()?;
+            let mut delta = (a0 + &norm_root) * &two_inv;
+            if delta.legendre_symbol() == LegendreSymbol::MinusOne {
+                delta = (a0 - norm_root) * two_inv;
+            }
+            let (x0, _) = delta.sqrt()?;
+            let x1 = a1 / (FieldElement::from(2) * &x0);
+            (x0, x1)
+        };
+
+        let root = Self::new([x0, x1]);
+        let negated_root = -&root;
+        Some((root, negated_root))
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<Q> IsConstantTimeField for QuadraticExtensionField<Q>
+where
+    Q: Clone + Debug + HasQuadraticNonResidue,
+    Q::BaseField: IsConstantTimeField,
+{
+}
+
+/// Samples every component independently and uniformly at random.
+impl<Q> RandomSample for FieldElement<QuadraticExtensionField<Q>>
+where
+    Q: Clone + Debug + HasQuadraticNonResidue,
+    FieldElement<Q::BaseField>: RandomSample,
+{
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new([
+            FieldElement::<Q::BaseField>::random(rng),
+            FieldElement::<Q::BaseField>::random(rng),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::field::fields::u64_prime_field::{U64FieldElement, U64PrimeField};
@@ -130,6 +194,34 @@ mod tests {
     #[allow(clippy::upper_case_acronyms)]
     type FEE = FieldElement<MyFieldExtensionBackend>;
 
+    #[test]
+    fn sqrt_of_every_square_is_found() {
+        for x0 in 0..ORDER_P {
+            for x1 in 0..ORDER_P {
+                let x = FEE::new([FE::new(x0), FE::new(x1)]);
+                let square = &x * &x;
+                let (root, negated_root) = square.sqrt().unwrap();
+                assert!(root == x || negated_root == x);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_of_a_non_square_is_none() {
+        // The norm of a square of the extension is a square of the base field.
+        let a = FEE::new([FE::new(0), FE::new(1)]);
+        assert_eq!((a.pow(2_u16) * (FEE::from(1) + &a)).sqrt(), None);
+        // Exactly half of the nonzero elements are squares.
+        let squares = (0..ORDER_P * ORDER_P)
+            .filter(|i| {
+                FEE::new([FE::new(i / ORDER_P), FE::new(i % ORDER_P)])
+                    .sqrt()
+                    .is_some()
+            })
+            .count() as u64;
+        assert_eq!(squares, (ORDER_P * ORDER_P - 1) / 2 + 1);
+    }
+
     #[test]
     fn test_add_1() {
         let a = FEE::new([FE::new(0), FE::new(3)]);
@@ -1,3 +1,61 @@
-pub mod u384_prime_field;
+/// Implementation of prime fields in Montgomery form over unsigned integers of any number of limbs.
+pub mod montgomery_backed_prime_fields;
 /// Implementation of prime fields over 64 bit unsigned integers.
 pub mod u64_prime_field;
+
+/// Former location of the Montgomery backed prime fields, which only supported 384 bits.
+#[deprecated(note = "use `montgomery_backed_prime_fields` instead")]
+pub mod u384_prime_field {
+    pub use super::montgomery_backed_prime_fields::*;
+    use crate::unsigned_integer::element::U384;
+
+    /// The configuration of the 384 bit Montgomery backed prime field, without the number
+    /// of limbs it used to lack. Every type implementing it is also a configuration of
+    /// `U384PrimeField`. `R2` and `MP` are ignored, since they are derived from `MODULUS`.
+    pub trait IsMontgomeryConfiguration {
+        const MODULUS: U384;
+        const R2: U384;
+        const MP: u64;
+    }
+
+    impl<C: IsMontgomeryConfiguration>
+        super::montgomery_backed_prime_fields::IsMontgomeryConfiguration<6> for C
+    {
+        const MODULUS: U384 = <C as IsMontgomeryConfiguration>::MODULUS;
+    }
+
+    /// The 384 bit Montgomery backed prime field, with the single type parameter it used to have.
+    pub type MontgomeryBackendPrimeField<C> =
+        super::montgomery_backed_prime_fields::U384PrimeField<C>;
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::u384_prime_field::{IsMontgomeryConfiguration, MontgomeryBackendPrimeField};
+    use crate::{
+        field::element::FieldElement,
+        unsigned_integer::element::{UnsignedInteger, U384},
+    };
+
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfig23;
+    impl IsMontgomeryConfiguration for MontgomeryConfig23 {
+        const MODULUS: U384 = UnsignedInteger::from_u64(23);
+        const R2: U384 = UnsignedInteger::from_u64(6);
+        const MP: u64 = 3208129404123400281;
+    }
+
+    type F23Element = FieldElement<MontgomeryBackendPrimeField<MontgomeryConfig23>>;
+
+    #[test]
+    fn configurations_of_the_former_module_still_work() {
+        let x = F23Element::from(11);
+        let y = F23Element::from(10);
+        assert_eq!(x * y, F23Element::from(110));
+        assert_eq!(
+            F23Element::from(5).inv() * F23Element::from(5),
+            F23Element::one()
+        );
+    }
+}
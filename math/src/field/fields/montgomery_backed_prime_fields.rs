@@ -0,0 +1,813 @@
+#[cfg(feature = "constant-time")]
+use crate::constant_time::{Choice, ConditionallySelectable, ConstantTimeEq, IsConstantTimeField};
+use crate::errors::ParseFieldElementError;
+use crate::field::element::FieldElement;
+use crate::traits::{ByteConversion, RandomSample};
+use crate::{
+    field::traits::{IsField, IsPrimeField},
+    unsigned_integer::element::UnsignedInteger,
+    unsigned_integer::montgomery::MontgomeryAlgorithms,
+};
+use rand::Rng;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Prime field of elements of 256 bits with Montgomery multiplication.
+pub type U256PrimeField<C> = MontgomeryBackendPrimeField<C, 4>;
+/// Prime field of elements of 384 bits with Montgomery multiplication.
+pub type U384PrimeField<C> = MontgomeryBackendPrimeField<C, 6>;
+
+/// This trait is necessary for us to be able to use unsigned integer types bigger than
+/// `u128` (the biggest native `unit`) as constant generics.
+/// This trait should be removed when Rust supports this feature.
+///
+/// The constants of the Montgomery representation are derived from `MODULUS` at compile
+/// time. A modulus that does not fit in `NUM_LIMBS` limbs is rejected by the constant
+/// parser `UnsignedInteger::from`, and one that is even or smaller than 3 is rejected
+/// when compiling the field:
+///
+/// ```compile_fail
+/// use lambdaworks_math::field::element::FieldElement;
+/// use lambdaworks_math::field::fields::montgomery_backed_prime_fields::{
+///     IsMontgomeryConfiguration, U256PrimeField,
+/// };
+/// use lambdaworks_math::unsigned_integer::element::U256;
+///
+/// #[derive(Clone, Debug)]
+/// struct EvenModulus;
+/// impl IsMontgomeryConfiguration<4> for EvenModulus {
+///     const MODULUS: U256 = U256::from_u64(24);
+/// }
+///
+/// let x = FieldElement::<U256PrimeField<EvenModulus>>::from(2);
+/// ```
+pub trait IsMontgomeryConfiguration<const NUM_LIMBS: usize> {
+    const MODULUS: UnsignedInteger<NUM_LIMBS>;
+}
+
+/// Prime field whose elements are stored in Montgomery form as unsigned integers
+/// of `NUM_LIMBS` limbs of 64 bits. The modulus must fit in `NUM_LIMBS` limbs.
+#[derive(Clone, Debug)]
+pub struct MontgomeryBackendPrimeField<C, const NUM_LIMBS: usize> {
+    phantom: PhantomData<C>,
+}
+
+impl<C, const NUM_LIMBS: usize> MontgomeryBackendPrimeField<C, NUM_LIMBS>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS>,
+{
+    const ZERO: UnsignedInteger<NUM_LIMBS> = UnsignedInteger::from_u64(0);
+    /// `R^2 mod MODULUS`, where `R = 2^(64 * NUM_LIMBS)`.
+    pub const R2: UnsignedInteger<NUM_LIMBS> = MontgomeryAlgorithms::compute_r2(&C::MODULUS);
+    /// `-MODULUS^{-1} mod 2^64`.
+    pub const MP: u64 = MontgomeryAlgorithms::compute_mp(&C::MODULUS);
+}
+
+impl<C, const NUM_LIMBS: usize> IsField for MontgomeryBackendPrimeField<C, NUM_LIMBS>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    type BaseType = UnsignedInteger<NUM_LIMBS>;
+
+    #[cfg(not(feature = "constant-time"))]
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let (sum, overflow) = UnsignedInteger::add(a, b);
+        if !overflow {
+            if sum < C::MODULUS {
+                sum
+            } else {
+                sum - C::MODULUS
+            }
+        } else {
+            let (diff, _) = UnsignedInteger::sub(&sum, &C::MODULUS);
+            diff
+        }
+    }
+
+    /// Computes the sum without branches. The sum is reduced if it overflows
+    /// or if subtracting the modulus does not borrow.
+    #[cfg(feature = "constant-time")]
+    fn add(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let (sum, overflow) = UnsignedInteger::add(a, b);
+        let (reduced, borrow) = UnsignedInteger::sub(&sum, &C::MODULUS);
+        UnsignedInteger::conditional_select(
+            &sum,
+            &reduced,
+            Choice::from(overflow) | !Choice::from(borrow),
+        )
+    }
+
+    fn mul(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        MontgomeryAlgorithms::cios(a, b, &C::MODULUS, &Self::MP)
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        if b <= a {
+            a - b
+        } else {
+            C::MODULUS - (b - a)
+        }
+    }
+
+    /// Computes the difference without branches, adding the modulus when it borrows.
+    #[cfg(feature = "constant-time")]
+    fn sub(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        let (difference, borrow) = UnsignedInteger::sub(a, b);
+        let (corrected, _) = UnsignedInteger::add(&difference, &C::MODULUS);
+        UnsignedInteger::conditional_select(&difference, &corrected, Choice::from(borrow))
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        if a == &Self::ZERO {
+            *a
+        } else {
+            C::MODULUS - a
+        }
+    }
+
+    /// Computes the negation without branches.
+    #[cfg(feature = "constant-time")]
+    fn neg(a: &Self::BaseType) -> Self::BaseType {
+        let (negation, _) = UnsignedInteger::sub(&C::MODULUS, a);
+        UnsignedInteger::conditional_select(&negation, &Self::ZERO, a.ct_eq(&Self::ZERO))
+    }
+
+    fn inv(a: &Self::BaseType) -> Self::BaseType {
+        if a == &Self::ZERO {
+            panic!("Division by zero error.")
+        }
+        MontgomeryAlgorithms::inv_binary_gcd(a, &C::MODULUS, &Self::R2, &Self::MP)
+    }
+
+    fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType {
+        Self::mul(a, &Self::inv(b))
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a == b
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn eq(a: &Self::BaseType, b: &Self::BaseType) -> bool {
+        a.ct_eq(b).into()
+    }
+
+    fn zero() -> Self::BaseType {
+        Self::ZERO
+    }
+
+    fn one() -> Self::BaseType {
+        Self::from_u64(1)
+    }
+
+    fn from_u64(x: u64) -> Self::BaseType {
+        MontgomeryAlgorithms::cios(
+            &UnsignedInteger::from_u64(x),
+            &Self::R2,
+            &C::MODULUS,
+            &Self::MP,
+        )
+    }
+
+    fn from_base_type(x: Self::BaseType) -> Self::BaseType {
+        MontgomeryAlgorithms::cios(&x, &Self::R2, &C::MODULUS, &Self::MP)
+    }
+}
+
+impl<C, const NUM_LIMBS: usize> IsPrimeField for MontgomeryBackendPrimeField<C, NUM_LIMBS>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    type RepresentativeType = UnsignedInteger<NUM_LIMBS>;
+
+    fn representative(a: &Self::BaseType) -> Self::BaseType {
+        MontgomeryAlgorithms::cios(a, &UnsignedInteger::from_u64(1), &C::MODULUS, &Self::MP)
+    }
+
+    fn modulus_minus_one() -> Self::RepresentativeType {
+        C::MODULUS - UnsignedInteger::from_u64(1)
+    }
+}
+
+#[cfg(feature = "constant-time")]
+impl<C, const NUM_LIMBS: usize> IsConstantTimeField for MontgomeryBackendPrimeField<C, NUM_LIMBS> where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug
+{
+}
+
+impl<C, const NUM_LIMBS: usize> ByteConversion
+    for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.representative().to_bytes_be()
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.representative().to_bytes_le()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::<NUM_LIMBS>::from_bytes_be(bytes)?;
+        Ok(Self::new(value))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError> {
+        let value = UnsignedInteger::<NUM_LIMBS>::from_bytes_le(bytes)?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<C, const NUM_LIMBS: usize> FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    /// Returns the multiplicative inverse of `self` in time independent of its value,
+    /// to be used when `self` is secret. Returns zero if `self` is zero.
+    pub fn inv_constant_time(&self) -> Self {
+        Self::from(&MontgomeryAlgorithms::inv_binary_gcd_constant_time(
+            self.value(),
+            &C::MODULUS,
+            &MontgomeryBackendPrimeField::<C, NUM_LIMBS>::R2,
+            &MontgomeryBackendPrimeField::<C, NUM_LIMBS>::MP,
+        ))
+    }
+}
+
+/// Formats the representative of the element in decimal.
+impl<C, const NUM_LIMBS: usize> fmt::Display
+    for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.representative(), f)
+    }
+}
+
+/// Formats the representative of the element in lowercase hexadecimal.
+impl<C, const NUM_LIMBS: usize> fmt::LowerHex
+    for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.representative(), f)
+    }
+}
+
+/// Formats the representative of the element in uppercase hexadecimal.
+impl<C, const NUM_LIMBS: usize> fmt::UpperHex
+    for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.representative(), f)
+    }
+}
+
+impl<C, const NUM_LIMBS: usize> FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    /// Parses the representative of the element from a hexadecimal string, without prefix.
+    /// Fails if the representative is not smaller than the modulus.
+    pub fn from_hex_str(hex_str: &str) -> Result<Self, ParseFieldElementError> {
+        Self::from_representative(UnsignedInteger::from_hex_str(hex_str)?)
+    }
+
+    /// Parses the representative of the element from a decimal string.
+    /// Fails if the representative is not smaller than the modulus.
+    pub fn from_dec_str(dec_str: &str) -> Result<Self, ParseFieldElementError> {
+        Self::from_representative(UnsignedInteger::from_dec_str(dec_str)?)
+    }
+
+    fn from_representative(
+        representative: UnsignedInteger<NUM_LIMBS>,
+    ) -> Result<Self, ParseFieldElementError> {
+        if representative >= C::MODULUS {
+            return Err(ParseFieldElementError::NotReduced);
+        }
+        Ok(Self::new(representative))
+    }
+}
+
+/// Parses the representative of the element in decimal, or in hexadecimal if it starts
+/// with `0x`, which reads back the output of `Display` and of the alternate `LowerHex`.
+impl<C, const NUM_LIMBS: usize> FromStr for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    type Err = ParseFieldElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_representative(UnsignedInteger::from_str(s)?)
+    }
+}
+
+/// Samples the representative uniformly in `[0, MODULUS)`.
+impl<C, const NUM_LIMBS: usize> RandomSample
+    for FieldElement<MontgomeryBackendPrimeField<C, NUM_LIMBS>>
+where
+    C: IsMontgomeryConfiguration<NUM_LIMBS> + Clone + Debug,
+{
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(UnsignedInteger::random_below(rng, &C::MODULUS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::{ParseFieldElementError, ParseUnsignedIntegerError},
+        field::{element::FieldElement, traits::LegendreSymbol},
+        traits::{ByteConversion, RandomSample},
+        unsigned_integer::element::{UnsignedInteger, U256, U384},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{
+        IsMontgomeryConfiguration, MontgomeryBackendPrimeField, U256PrimeField, U384PrimeField,
+    };
+    use crate::field::fields::u64_prime_field::U64FieldElement;
+
+    // F23
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfig23;
+    impl IsMontgomeryConfiguration<6> for MontgomeryConfig23 {
+        const MODULUS: U384 = UnsignedInteger::from_u64(23);
+    }
+
+    type F23 = U384PrimeField<MontgomeryConfig23>;
+    type F23Element = FieldElement<F23>;
+
+    #[test]
+    fn montgomery_backend_multiplication_works_0() {
+        let x = F23Element::from(11_u64);
+        let y = F23Element::from(10_u64);
+        let c = F23Element::from(110_u64);
+        assert_eq!(x * y, c);
+    }
+
+    const ORDER: usize = 23;
+    #[test]
+    fn two_plus_one_is_three() {
+        assert_eq!(
+            F23Element::from(2) + F23Element::from(1),
+            F23Element::from(3)
+        );
+    }
+
+    #[test]
+    fn max_order_plus_1_is_0() {
+        assert_eq!(
+            F23Element::from((ORDER - 1) as u64) + F23Element::from(1),
+            F23Element::from(0)
+        );
+    }
+
+    #[test]
+    fn when_comparing_13_and_13_they_are_equal() {
+        let a: F23Element = F23Element::from(13);
+        let b: F23Element = F23Element::from(13);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn when_comparing_13_and_8_they_are_different() {
+        let a: F23Element = F23Element::from(13);
+        let b: F23Element = F23Element::from(8);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn mul_neutral_element() {
+        let a: F23Element = F23Element::from(1);
+        let b: F23Element = F23Element::from(2);
+        assert_eq!(a * b, F23Element::from(2));
+    }
+
+    #[test]
+    fn mul_2_3_is_6() {
+        let a: F23Element = F23Element::from(2);
+        let b: F23Element = F23Element::from(3);
+        assert_eq!(a * b, F23Element::from(6));
+    }
+
+    #[test]
+    fn mul_order_minus_1() {
+        let a: F23Element = F23Element::from((ORDER - 1) as u64);
+        let b: F23Element = F23Element::from((ORDER - 1) as u64);
+        assert_eq!(a * b, F23Element::from(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn inv_0_error() {
+        F23Element::from(0).inv();
+    }
+
+    #[test]
+    fn inv_2() {
+        let a: F23Element = F23Element::from(2);
+        assert_eq!(&a * a.inv(), F23Element::from(1));
+    }
+
+    #[test]
+    fn pow_2_3() {
+        assert_eq!(F23Element::from(2).pow(3_u64), F23Element::from(8))
+    }
+
+    #[test]
+    fn pow_p_minus_1() {
+        assert_eq!(F23Element::from(2).pow(ORDER - 1), F23Element::from(1))
+    }
+
+    #[test]
+    fn div_1() {
+        assert_eq!(
+            F23Element::from(2) / F23Element::from(1),
+            F23Element::from(2)
+        )
+    }
+
+    #[test]
+    fn div_4_2() {
+        assert_eq!(
+            F23Element::from(4) / F23Element::from(2),
+            F23Element::from(2)
+        )
+    }
+
+    #[test]
+    fn div_4_3() {
+        assert_eq!(
+            F23Element::from(4) / F23Element::from(3) * F23Element::from(3),
+            F23Element::from(4)
+        )
+    }
+
+    #[test]
+    fn two_plus_its_additive_inv_is_0() {
+        let two = F23Element::from(2);
+
+        assert_eq!(&two + (-&two), F23Element::from(0))
+    }
+
+    #[test]
+    fn four_minus_three_is_1() {
+        let four = F23Element::from(4);
+        let three = F23Element::from(3);
+
+        assert_eq!(four - three, F23Element::from(1))
+    }
+
+    #[test]
+    fn zero_minus_1_is_order_minus_1() {
+        let zero = F23Element::from(0);
+        let one = F23Element::from(1);
+
+        assert_eq!(zero - one, F23Element::from((ORDER - 1) as u64))
+    }
+
+    #[test]
+    fn sqrt_works_when_the_modulus_is_3_mod_4() {
+        for i in 0..ORDER as u64 {
+            let x = F23Element::from(i);
+            let (root, negated_root) = (&x * &x).sqrt().unwrap();
+            assert!(root == x || negated_root == x);
+        }
+        assert_eq!(F23Element::from(5).sqrt(), None);
+    }
+
+    #[test]
+    fn neg_zero_is_zero() {
+        let zero = F23Element::from(0);
+
+        assert_eq!(-&zero, zero);
+    }
+
+    // FP1
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfigP1;
+    impl IsMontgomeryConfiguration<6> for MontgomeryConfigP1 {
+        const MODULUS: U384 = UnsignedInteger {
+            limbs: [
+                0,
+                0,
+                0,
+                3450888597,
+                5754816256417943771,
+                15923941673896418529,
+            ],
+        };
+    }
+
+    #[test]
+    fn montgomery_prime_field_addition_works_0() {
+        let x = FP1Element::new(UnsignedInteger::from(
+            "05ed176deb0e80b4deb7718cdaa075165f149c",
+        ));
+        let y = FP1Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        let c = FP1Element::new(UnsignedInteger::from(
+            "64fd5279bf47fe02d4185ce279d8aa55e00352",
+        ));
+        assert_eq!(x + y, c);
+    }
+
+    type FP1 = U384PrimeField<MontgomeryConfigP1>;
+    type FP1Element = FieldElement<FP1>;
+    #[test]
+    fn montgomery_prime_field_multiplication_works_0() {
+        let x = FP1Element::new(UnsignedInteger::from(
+            "05ed176deb0e80b4deb7718cdaa075165f149c",
+        ));
+        let y = FP1Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        let c = FP1Element::new(UnsignedInteger::from(
+            "73d23e8d462060dc23d5c15c00fc432d95621a3c",
+        ));
+        assert_eq!(x * y, c);
+    }
+
+    // FP2
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfigP2;
+    impl IsMontgomeryConfiguration<6> for MontgomeryConfigP2 {
+        const MODULUS: U384 = UnsignedInteger {
+            limbs: [
+                18446744073709551615,
+                18446744073709551615,
+                18446744073709551615,
+                18446744073709551615,
+                18446744073709551615,
+                18446744073709551275,
+            ],
+        };
+    }
+
+    type FP2 = U384PrimeField<MontgomeryConfigP2>;
+    type FP2Element = FieldElement<FP2>;
+
+    #[test]
+    fn montgomery_prime_field_addition_works_1() {
+        let x = FP2Element::new(UnsignedInteger::from(
+            "05ed176deb0e80b4deb7718cdaa075165f149c",
+        ));
+        let y = FP2Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        let c = FP2Element::new(UnsignedInteger::from(
+            "64fd5279bf47fe02d4185ce279d8aa55e00352",
+        ));
+        assert_eq!(x + y, c);
+    }
+
+    #[test]
+    fn sqrt_with_tonelli_shanks_works() {
+        // The modulus of FP1 is 1 mod 4.
+        let x = FP1Element::new(UnsignedInteger::from(
+            "05ed176deb0e80b4deb7718cdaa075165f149c",
+        ));
+        let square = &x * &x;
+        assert_eq!(square.legendre_symbol(), LegendreSymbol::One);
+        let (root, negated_root) = square.sqrt().unwrap();
+        assert!(root == x || negated_root == x);
+        assert_eq!(root, -negated_root);
+    }
+
+    #[test]
+    fn sqrt_of_a_non_square_is_none() {
+        let non_square = FP1Element::from(3);
+        assert_eq!(non_square.legendre_symbol(), LegendreSymbol::MinusOne);
+        assert_eq!(non_square.sqrt(), None);
+    }
+
+    #[test]
+    fn representative_is_not_in_montgomery_form() {
+        let x = FP1Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        assert_eq!(
+            x.representative(),
+            UnsignedInteger::from("5f103b0bd4397d4df560eb559f38353f80eeb6")
+        );
+    }
+
+    #[test]
+    fn montgomery_prime_field_multiplication_works_1() {
+        let x = FP2Element::one();
+        let y = FP2Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        assert_eq!(&y * x, y);
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_be_is_the_identity() {
+        let x = FP2Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        assert_eq!(FP2Element::from_bytes_be(&x.to_bytes_be()).unwrap(), x);
+    }
+
+    #[test]
+    fn from_bytes_to_bytes_be_is_the_identity_for_one() {
+        let bytes = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        ];
+        assert_eq!(
+            FP2Element::from_bytes_be(&bytes).unwrap().to_bytes_be(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_le_is_the_identity() {
+        let x = FP2Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6",
+        ));
+        assert_eq!(FP2Element::from_bytes_le(&x.to_bytes_le()).unwrap(), x);
+    }
+
+    #[test]
+    fn from_bytes_to_bytes_le_is_the_identity_for_one() {
+        let bytes = vec![
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            FP2Element::from_bytes_le(&bytes).unwrap().to_bytes_le(),
+            bytes
+        );
+    }
+
+    // Goldilocks, with a single limb
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfigGoldilocks;
+    impl IsMontgomeryConfiguration<1> for MontgomeryConfigGoldilocks {
+        const MODULUS: UnsignedInteger<1> = UnsignedInteger::from_u64(0xffffffff00000001);
+    }
+
+    type Goldilocks = MontgomeryBackendPrimeField<MontgomeryConfigGoldilocks, 1>;
+    type GoldilocksElement = FieldElement<Goldilocks>;
+
+    #[test]
+    fn single_limb_field_matches_u64_prime_field() {
+        type Reference = U64FieldElement<0xffffffff00000001>;
+        let (a, b) = (0xfffffffe12345678_u64, 0x9abcdef012345678_u64);
+        let (x, y) = (GoldilocksElement::from(a), GoldilocksElement::from(b));
+        let (u, v) = (Reference::from(a), Reference::from(b));
+        assert_eq!((&x * &y).representative().limbs[0], *(u * v).value());
+        assert_eq!((&x + &y).representative().limbs[0], *(u + v).value());
+        assert_eq!((&x - &y).representative().limbs[0], *(u - v).value());
+        assert_eq!(&x * x.inv(), GoldilocksElement::one());
+        assert_eq!(x.to_bytes_be().len(), 8);
+    }
+
+    // 256-bit field, with the scalar field of BLS12-381 as modulus
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfig256;
+    impl IsMontgomeryConfiguration<4> for MontgomeryConfig256 {
+        const MODULUS: U256 =
+            U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+    }
+
+    type FP256Element = FieldElement<U256PrimeField<MontgomeryConfig256>>;
+
+    #[test]
+    fn field_of_four_limbs_works() {
+        let x = FP256Element::new(U256::from("5f103b0bd4397d4df560eb559f38353f80eeb6"));
+        let minus_one = FP256Element::new(U256::from(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+        ));
+        assert_eq!(minus_one, -FP256Element::one());
+        assert_eq!(&x * x.inv(), FP256Element::one());
+        assert_eq!(&x * &minus_one, -&x);
+        assert_eq!(x.to_bytes_be().len(), 32);
+        assert_eq!(FP256Element::from_bytes_le(&x.to_bytes_le()).unwrap(), x);
+    }
+
+    // 448-bit field, with the modulus 2^448 - 2^224 - 1 of Ed448
+    #[derive(Clone, Debug)]
+    struct MontgomeryConfig448;
+    impl IsMontgomeryConfiguration<7> for MontgomeryConfig448 {
+        const MODULUS: UnsignedInteger<7> = UnsignedInteger::from("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+    }
+
+    type FP448Element = FieldElement<MontgomeryBackendPrimeField<MontgomeryConfig448, 7>>;
+
+    #[test]
+    fn field_of_seven_limbs_works() {
+        let x = FP448Element::new(UnsignedInteger::from(
+            "5f103b0bd4397d4df560eb559f38353f80eeb6fffffffffffffffffffffffffffffffffffffffffffffff",
+        ));
+        assert_eq!(&x * x.inv(), FP448Element::one());
+        assert_eq!(
+            FP448Element::from(2).pow(448_u64),
+            FP448Element::from(2).pow(224_u64) + FP448Element::one()
+        );
+        assert_eq!(x.to_bytes_be().len(), 56);
+        assert_eq!(FP448Element::from_bytes_be(&x.to_bytes_be()).unwrap(), x);
+    }
+
+    #[test]
+    fn elements_are_formatted_by_their_representative() {
+        let x = FP256Element::new(U256::from("5f103b0bd4397d4df560eb559f38353f80eeb6"));
+        assert_eq!(format!("{x:x}"), "5f103b0bd4397d4df560eb559f38353f80eeb6");
+        assert_eq!(
+            format!("{x:#X}"),
+            "0x5F103B0BD4397D4DF560EB559F38353F80EEB6"
+        );
+        assert_eq!(
+            format!("{x}"),
+            "2119984682818406610399754315228259474349289142"
+        );
+        assert_eq!(
+            format!("{}", -FP256Element::one()),
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+        );
+    }
+
+    #[test]
+    fn parsing_reads_back_the_formatted_elements() {
+        let x = FP256Element::new(U256::from("5f103b0bd4397d4df560eb559f38353f80eeb6"));
+        for element in [x, -FP256Element::one(), FP256Element::zero()] {
+            assert_eq!(format!("{element}").parse(), Ok(element.clone()));
+            assert_eq!(format!("{element:#x}").parse(), Ok(element.clone()));
+            assert_eq!(
+                FP256Element::from_hex_str(&format!("{element:X}")),
+                Ok(element.clone())
+            );
+            assert_eq!(
+                FP256Element::from_dec_str(&format!("{element}")),
+                Ok(element)
+            );
+        }
+    }
+
+    #[test]
+    fn parsing_rejects_values_not_smaller_than_the_modulus() {
+        assert_eq!(
+            "23".parse::<F23Element>(),
+            Err(ParseFieldElementError::NotReduced)
+        );
+        assert_eq!(
+            F23Element::from_hex_str("18"),
+            Err(ParseFieldElementError::NotReduced)
+        );
+        assert_eq!("0x16".parse::<F23Element>(), Ok(F23Element::from(22)));
+        assert_eq!(
+            "2z".parse::<F23Element>(),
+            Err(ParseFieldElementError::InvalidInteger(
+                ParseUnsignedIntegerError::InvalidDigit
+            ))
+        );
+    }
+
+    #[test]
+    fn inv_matches_fermat_inversion_for_every_element() {
+        let p_minus_two = U384::from_u64(21);
+        for i in 1..23 {
+            let x = F23Element::from(i);
+            assert_eq!(x.inv(), x.pow(p_minus_two));
+            assert_eq!(x.inv_constant_time(), x.pow(p_minus_two));
+        }
+    }
+
+    #[test]
+    fn inv_constant_time_of_zero_is_zero() {
+        assert_eq!(F23Element::zero().inv_constant_time(), F23Element::zero());
+        assert_eq!(
+            FP448Element::zero().inv_constant_time(),
+            FP448Element::zero()
+        );
+    }
+
+    #[test]
+    fn inv_of_random_elements_works() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let x = GoldilocksElement::random(&mut rng);
+            assert_eq!(&x * x.inv(), GoldilocksElement::one());
+            assert_eq!(x.inv_constant_time(), x.inv());
+            let x = FP256Element::random(&mut rng);
+            assert_eq!(&x * x.inv(), FP256Element::one());
+            assert_eq!(x.inv_constant_time(), x.inv());
+            // The modulus is close to 2^448, so halving modulo it overflows the limbs.
+            let x = FP448Element::random(&mut rng);
+            assert_eq!(&x * x.inv(), FP448Element::one());
+            assert_eq!(x.inv_constant_time(), x.inv());
+        }
+    }
+}
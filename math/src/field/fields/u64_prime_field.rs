@@ -1,8 +1,9 @@
-use crate::cyclic_group::IsGroup;
+use crate::cyclic_group::{IsGroup, IsGroupWithNegation};
 use crate::errors::ByteConversionError::{FromBEBytesError, FromLEBytesError};
 use crate::field::element::FieldElement;
-use crate::field::traits::IsField;
-use crate::traits::ByteConversion;
+use crate::field::traits::{IsField, IsPrimeField};
+use crate::traits::{ByteConversion, RandomSample};
+use rand::Rng;
 
 /// Type representing prime fields over unsigned 64-bit integers.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -58,6 +59,18 @@ impl<const MODULUS: u64> IsField for U64PrimeField<MODULUS> {
     }
 }
 
+impl<const MODULUS: u64> IsPrimeField for U64PrimeField<MODULUS> {
+    type RepresentativeType = u64;
+
+    fn representative(a: &u64) -> u64 {
+        Self::from_u64(*a)
+    }
+
+    fn modulus_minus_one() -> u64 {
+        MODULUS - 1
+    }
+}
+
 impl<const MODULUS: u64> Copy for U64FieldElement<MODULUS> {}
 
 /// Represents an element in Fp. (E.g: 0, 1, 2 are the elements of F3)
@@ -75,6 +88,12 @@ impl<const MODULUS: u64> IsGroup for U64FieldElement<MODULUS> {
     }
 }
 
+impl<const MODULUS: u64> IsGroupWithNegation for U64FieldElement<MODULUS> {
+    fn neg(&self) -> Self {
+        -*self
+    }
+}
+
 impl<const MODULUS: u64> ByteConversion for U64FieldElement<MODULUS> {
     fn to_bytes_be(&self) -> Vec<u8> {
         u64::to_be_bytes(*self.value()).into()
@@ -95,9 +114,16 @@ impl<const MODULUS: u64> ByteConversion for U64FieldElement<MODULUS> {
     }
 }
 
+impl<const MODULUS: u64> RandomSample for FieldElement<U64PrimeField<MODULUS>> {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from(rng.gen_range(0..MODULUS))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::field::traits::LegendreSymbol;
     const MODULUS: u64 = 13;
     type FE = FieldElement<U64PrimeField<MODULUS>>;
 
@@ -226,6 +252,55 @@ mod tests {
         assert_eq!(a * b, a.operate_with_self(12));
     }
 
+    #[test]
+    fn representative_of_an_element_is_reduced() {
+        assert_eq!(FE::from(MODULUS + 3).representative(), 3);
+    }
+
+    #[test]
+    fn legendre_symbol_distinguishes_squares() {
+        assert_eq!(FE::new(0).legendre_symbol(), LegendreSymbol::Zero);
+        assert_eq!(FE::new(4).legendre_symbol(), LegendreSymbol::One);
+        assert_eq!(FE::new(2).legendre_symbol(), LegendreSymbol::MinusOne);
+    }
+
+    #[test]
+    fn sqrt_of_every_square_is_found_with_tonelli_shanks() {
+        // 13 = 1 mod 4
+        for x in 0..MODULUS {
+            let square = FE::new(x) * FE::new(x);
+            let (root, negated_root) = square.sqrt().unwrap();
+            assert_eq!(root * root, square);
+            assert_eq!(negated_root, -root);
+            assert!(root == FE::new(x) || negated_root == FE::new(x));
+        }
+    }
+
+    #[test]
+    fn sqrt_of_every_square_is_found_when_the_modulus_is_3_mod_4() {
+        type F19 = U64FieldElement<19>;
+        for x in 0..19 {
+            let square = F19::new(x) * F19::new(x);
+            let (root, negated_root) = square.sqrt().unwrap();
+            assert!(root == F19::new(x) || negated_root == F19::new(x));
+        }
+    }
+
+    #[test]
+    fn sqrt_of_a_non_square_is_none() {
+        assert_eq!(FE::new(2).sqrt(), None);
+        assert_eq!(U64FieldElement::<19>::new(2).sqrt(), None);
+    }
+
+    #[test]
+    fn sqrt_with_a_large_two_adicity() {
+        // 257 - 1 = 2^8
+        type F257 = U64FieldElement<257>;
+        let square = F257::new(123).pow(2_u64);
+        let (root, negated_root) = square.sqrt().unwrap();
+        assert!(root == F257::new(123) || negated_root == F257::new(123));
+    }
+
     #[test]
     fn to_bytes_from_bytes_be_is_the_identity() {
         let x = FE::new(12345);
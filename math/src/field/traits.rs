@@ -1,7 +1,7 @@
 use crate::{fft::errors::FFTError, unsigned_integer::traits::IsUnsignedInteger};
 use std::fmt::Debug;
 
-use super::element::FieldElement;
+use super::{element::FieldElement, errors::FieldError};
 
 /// Trait to define necessary parameters for FFT-friendly Fields.
 /// Two-Adic fields are ones whose order is of the form  $2^n k + 1$.
@@ -75,6 +75,15 @@ pub trait IsField: Debug + Clone {
     /// Returns the multiplicative inverse of `a`.
     fn inv(a: &Self::BaseType) -> Self::BaseType;
 
+    /// Returns the multiplicative inverse of `a`, or an error if `a` is zero.
+    fn try_inv(a: &Self::BaseType) -> Result<Self::BaseType, FieldError> {
+        if Self::eq(a, &Self::zero()) {
+            Err(FieldError::DivisionByZero)
+        } else {
+            Ok(Self::inv(a))
+        }
+    }
+
     /// Returns the division of `a` and `b`.
     fn div(a: &Self::BaseType, b: &Self::BaseType) -> Self::BaseType;
 
@@ -94,3 +103,97 @@ pub trait IsField: Debug + Clone {
     /// of that element in the field.
     fn from_base_type(x: Self::BaseType) -> Self::BaseType;
 }
+
+/// The result of the Legendre symbol `(a / p)`: whether `a` is zero,
+/// a nonzero square or a non square modulo `p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendreSymbol {
+    MinusOne,
+    Zero,
+    One,
+}
+
+/// Trait for fields of prime order. Elements of these fields have a canonical
+/// representative: the unique integer in the range `[0, p)` congruent to them.
+pub trait IsPrimeField: IsField {
+    /// The type of the canonical representatives of the field elements.
+    type RepresentativeType: IsUnsignedInteger;
+
+    /// Returns the integer in the range `[0, p)` that represents `a`.
+    fn representative(a: &Self::BaseType) -> Self::RepresentativeType;
+
+    /// Returns `p - 1`, where `p` is the order of the field.
+    fn modulus_minus_one() -> Self::RepresentativeType;
+
+    /// Returns the Legendre symbol of `a`, computed with Euler's criterion
+    /// `a^((p - 1) / 2)`.
+    fn legendre_symbol(a: &Self::BaseType) -> LegendreSymbol {
+        let symbol = Self::pow(a, Self::modulus_minus_one() >> 1);
+        if Self::eq(&symbol, &Self::zero()) {
+            LegendreSymbol::Zero
+        } else if Self::eq(&symbol, &Self::one()) {
+            LegendreSymbol::One
+        } else {
+            LegendreSymbol::MinusOne
+        }
+    }
+
+    /// Returns the two square roots of `a`, or `None` if `a` is not a square.
+    /// Uses `a^((p + 1) / 4)` when `p = 3 mod 4` and the Tonelli-Shanks
+    /// algorithm otherwise. The order of the field must be odd.
+    fn sqrt(a: &Self::BaseType) -> Option<(Self::BaseType, Self::BaseType)> {
+        match Self::legendre_symbol(a) {
+            LegendreSymbol::Zero => return Some((Self::zero(), Self::zero())),
+            LegendreSymbol::MinusOne => return None,
+            LegendreSymbol::One => (),
+        }
+
+        let one = Self::RepresentativeType::from(1);
+        let p_minus_one = Self::modulus_minus_one();
+        let root = if p_minus_one & Self::RepresentativeType::from(3)
+            == Self::RepresentativeType::from(2)
+        {
+            // (p + 1) / 4 = (p - 3) / 4 + 1
+            Self::mul(&Self::pow(a, p_minus_one >> 2), a)
+        } else {
+            // Write p - 1 = q * 2^s with q odd.
+            let mut q = p_minus_one;
+            let mut s = 0;
+            while q & one != one {
+                q = q >> 1;
+                s += 1;
+            }
+
+            let mut non_square = 2;
+            while Self::legendre_symbol(&Self::from_u64(non_square)) != LegendreSymbol::MinusOne {
+                non_square += 1;
+            }
+
+            let mut m = s;
+            let mut c = Self::pow(&Self::from_u64(non_square), q);
+            let mut t = Self::pow(a, q);
+            // (q + 1) / 2 = (q - 1) / 2 + 1
+            let mut r = Self::mul(&Self::pow(a, q >> 1), a);
+            while !Self::eq(&t, &Self::one()) {
+                // Find the least i such that t^(2^i) = 1.
+                let mut i = 0;
+                let mut t_power = t.clone();
+                while !Self::eq(&t_power, &Self::one()) {
+                    t_power = Self::mul(&t_power, &t_power);
+                    i += 1;
+                }
+                let mut b = c;
+                for _ in 0..m - i - 1 {
+                    b = Self::mul(&b, &b);
+                }
+                m = i;
+                c = Self::mul(&b, &b);
+                t = Self::mul(&t, &c);
+                r = Self::mul(&r, &b);
+            }
+            r
+        };
+        let negated_root = Self::neg(&root);
+        Some((root, negated_root))
+    }
+}
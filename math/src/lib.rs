@@ -1,3 +1,5 @@
+#[cfg(feature = "constant-time")]
+pub mod constant_time;
 pub mod cyclic_group;
 pub mod elliptic_curve;
 pub mod errors;
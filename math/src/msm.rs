@@ -1,22 +1,29 @@
 use crate::cyclic_group::IsGroup;
-use crate::field::fields::u64_prime_field::U64FieldElement;
-
-// TODO: FE should be a generic field element. Need to implement BigInt first.
-const ORDER_R: u64 = 5;
-type FE = U64FieldElement<ORDER_R>;
+use crate::elliptic_curve::short_weierstrass::{
+    point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass,
+    xyzz::ShortWeierstrassXYZZPoint,
+};
+use crate::field::element::FieldElement;
+use crate::unsigned_integer::element::UnsignedInteger;
 
 /// This function computes the multiscalar multiplication (MSM).
 ///
 /// Assume a group G of order r is given.
 /// Let `hidings = [g_1, ..., g_n]` be a tuple of group points in G and
-/// let `cs = [k_1, ..., k_n]` be a tuple of scalars in the Galois field GF(r).
+/// let `cs = [k_1, ..., k_n]` be a tuple of scalars given by their
+/// representatives as unsigned integers.
 ///
 /// Then, with additive notation, `msm(cs, hidings)` computes k_1 * g_1 + .... + k_n * g_n.
 ///
 /// If `hidings` and `cs` are empty, then `msm` returns the zero element of the group.
 ///
+/// Scalars of a prime field can be passed through `FieldElement::representative`.
+///
+/// The computation uses Pippenger's bucket method with a window size chosen
+/// according to the number of terms.
+///
 /// Panics if `cs` and `hidings` have different lengths.
-pub fn msm<T>(cs: &[FE], hidings: &[T]) -> T
+pub fn msm<const NUM_LIMBS: usize, T>(cs: &[UnsignedInteger<NUM_LIMBS>], hidings: &[T]) -> T
 where
     T: IsGroup,
 {
@@ -25,37 +32,165 @@ where
         hidings.len(),
         "Slices `cs` and `hidings` must be of the same length to compute `msm`."
     );
-    cs.iter()
-        .zip(hidings.iter())
-        .map(|(&c, h)| h.operate_with_self(*c.value() as u128))
-        .reduce(|acc, x| acc.operate_with(&x))
-        .unwrap_or_else(T::neutral_element)
+    pippenger(cs, hidings, optimal_window_size(cs.len()), T::operate_with)
+}
+
+/// Computes the MSM of points of a short Weierstrass curve, like `msm`, but accumulates
+/// the buckets of Pippenger's algorithm in XYZZ coordinates. The points are brought to
+/// affine coordinates first, so that every point is added to its bucket with a mixed addition.
+///
+/// Points that are already affine, such as the ones built with `from_affine`, are used as
+/// they are. Any other point costs an inversion, which is worth it only when the points are
+/// reused in several MSMs.
+///
+/// Panics if `cs` and `points` have different lengths.
+pub fn msm_with_xyzz_buckets<const NUM_LIMBS: usize, E>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[ShortWeierstrassProjectivePoint<E>],
+) -> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass,
+{
+    assert_eq!(
+        cs.len(),
+        points.len(),
+        "Slices `cs` and `points` must be of the same length to compute `msm`."
+    );
+    let (cs, affine_points): (Vec<_>, Vec<_>) = cs
+        .iter()
+        .zip(points)
+        .filter(|(_, point)| !point.is_neutral_element())
+        .map(|(c, point)| {
+            let point = if point.z() == &FieldElement::one() {
+                point.clone()
+            } else {
+                point.to_affine()
+            };
+            (*c, ShortWeierstrassXYZZPoint::from(point))
+        })
+        .unzip();
+    pippenger(
+        &cs,
+        &affine_points,
+        optimal_window_size(cs.len()),
+        ShortWeierstrassXYZZPoint::operate_with_affine,
+    )
+    .into()
+}
+
+/// Returns a window size that approximately minimizes the number of group
+/// operations performed by Pippenger's algorithm for `n` terms.
+fn optimal_window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // ln(n) approximated as log2(n) * 69 / 100
+        (n.ilog2() as usize * 69 / 100) + 2
+    }
+}
+
+/// Returns the integer formed by the bits `[start, start + window_size)` of `c`,
+/// where bit 0 is the least significant one.
+fn window_digit<const NUM_LIMBS: usize>(
+    c: &UnsignedInteger<NUM_LIMBS>,
+    start: usize,
+    window_size: usize,
+) -> usize {
+    let end = (start + window_size).min(64 * NUM_LIMBS);
+    (start..end).rev().fold(0, |digit, bit| {
+        let limb = c.limbs[NUM_LIMBS - 1 - bit / 64];
+        (digit << 1) | ((limb >> (bit % 64)) & 1) as usize
+    })
+}
+
+/// Pippenger's bucket method.
+/// The scalars are split in windows of `window_size` bits. For each window, every point
+/// is added to the bucket indexed by its scalar digit, and the buckets are combined with
+/// a running sum. The partial results of the windows are then joined by doubling.
+/// The buckets are elements of `B`, and `add_to_bucket` adds a term to one of them.
+/// See "Faster batch forgery identification", Section 4 (https://eprint.iacr.org/2012/549).
+fn pippenger<const NUM_LIMBS: usize, T, B>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    hidings: &[T],
+    window_size: usize,
+    add_to_bucket: impl Fn(&B, &T) -> B,
+) -> B
+where
+    B: IsGroup,
+{
+    let num_windows = (64 * NUM_LIMBS).div_ceil(window_size);
+    let mut buckets = vec![B::neutral_element(); (1 << window_size) - 1];
+    let mut result = B::neutral_element();
+
+    for window in (0..num_windows).rev() {
+        for _ in 0..window_size {
+            result = result.operate_with(&result);
+        }
+
+        buckets.iter_mut().for_each(|b| *b = B::neutral_element());
+        for (c, h) in cs.iter().zip(hidings) {
+            let digit = window_digit(c, window * window_size, window_size);
+            if digit != 0 {
+                buckets[digit - 1] = add_to_bucket(&buckets[digit - 1], h);
+            }
+        }
+
+        // sum_{i} i * buckets[i - 1] computed as the sum of the running sums.
+        let mut running_sum = B::neutral_element();
+        let mut window_sum = B::neutral_element();
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum.operate_with(bucket);
+            window_sum = window_sum.operate_with(&running_sum);
+        }
+        result = result.operate_with(&window_sum);
+    }
+    result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+    use crate::elliptic_curve::short_weierstrass::curves::bls12_381::field_extension::BLS12381PrimeField;
     use crate::elliptic_curve::short_weierstrass::curves::test_curve_1::TestCurve1;
     use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
     use crate::elliptic_curve::traits::IsEllipticCurve;
+    use crate::field::element::FieldElement;
+    use crate::field::fields::u64_prime_field::U64FieldElement;
+    use crate::unsigned_integer::element::{U256, U384};
+
+    const ORDER_R: u64 = 5;
+    type FE = U64FieldElement<ORDER_R>;
+    type U64 = UnsignedInteger<1>;
+
+    /// Computes `c * h` with Horner's rule over the limbs of `c`.
+    fn naive_operate_with_self<const NUM_LIMBS: usize, T: IsGroup>(
+        c: &UnsignedInteger<NUM_LIMBS>,
+        h: &T,
+    ) -> T {
+        c.limbs.iter().fold(T::neutral_element(), |acc, limb| {
+            acc.operate_with_self(1_u128 << 64)
+                .operate_with(&h.operate_with_self(*limb as u128))
+        })
+    }
 
     #[test]
     fn msm_11_is_1_over_elliptic_curves() {
-        let c = [FE::new(1)];
+        let c = [U64::from_u64(1)];
         let hiding = [TestCurve1::generator()];
         assert_eq!(msm(&c, &hiding), TestCurve1::generator());
     }
 
     #[test]
     fn msm_23_is_6_over_field_elements() {
-        let c = [FE::new(3)];
+        let c = [U64::from_u64(3)];
         let hiding = [FE::new(2)];
         assert_eq!(msm(&c, &hiding), FE::new(6));
     }
 
     #[test]
     fn msm_23_is_6_over_elliptic_curves() {
-        let c = [FE::new(3)];
+        let c = [U64::from_u64(3)];
         let g = TestCurve1::generator();
         let hiding = [g.operate_with_self(2)];
         assert_eq!(msm(&c, &hiding), g.operate_with_self(6));
@@ -63,14 +198,14 @@ mod tests {
 
     #[test]
     fn msm_with_c_2_3_hiding_3_4_is_18_over_field_elements() {
-        let c = [FE::new(2), FE::new(3)];
+        let c = [U64::from_u64(2), U64::from_u64(3)];
         let hiding = [FE::new(3), FE::new(4)];
         assert_eq!(msm(&c, &hiding), FE::new(18));
     }
 
     #[test]
     fn msm_with_c_2_3_hiding_3_4_is_18_over_elliptic_curves() {
-        let c = [FE::new(2), FE::new(3)];
+        let c = [U64::from_u64(2), U64::from_u64(3)];
         let g = TestCurve1::generator();
         let hiding = [g.operate_with_self(3), g.operate_with_self(4)];
         assert_eq!(msm(&c, &hiding), g.operate_with_self(18));
@@ -78,18 +213,83 @@ mod tests {
 
     #[test]
     fn msm_with_empty_input_over_field_elements() {
-        let c = [];
+        let c: [U64; 0] = [];
         let hiding: [FE; 0] = [];
         assert_eq!(msm(&c, &hiding), FE::new(0));
     }
 
     #[test]
     fn msm_with_empty_c_is_none_over_elliptic_curves() {
-        let c = [];
+        let c: [U64; 0] = [];
         let hiding: [ShortWeierstrassProjectivePoint<TestCurve1>; 0] = [];
         assert_eq!(
             msm(&c, &hiding),
             ShortWeierstrassProjectivePoint::neutral_element()
         );
     }
+
+    #[test]
+    fn window_digit_crosses_limb_boundaries() {
+        let c = U256::from("3000000000000000a");
+        assert_eq!(window_digit(&c, 0, 4), 10);
+        assert_eq!(window_digit(&c, 62, 4), 12);
+        assert_eq!(window_digit(&c, 254, 4), 0);
+    }
+
+    #[test]
+    fn pippenger_matches_naive_msm_for_every_window_size_over_bls12_381() {
+        let g = BLS12381Curve::generator();
+        let cs = [
+            U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000"),
+            U256::from("1f2a7c85e9d3c94b1e0f2a3d4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e"),
+            U256::from("0"),
+            U256::from("ffffffffffffffff"),
+        ];
+        let hidings: Vec<_> = (1..=cs.len() as u128)
+            .map(|i| g.operate_with_self(i))
+            .collect();
+        let expected = cs
+            .iter()
+            .zip(&hidings)
+            .map(|(c, h)| naive_operate_with_self(c, h))
+            .reduce(|acc, x| acc.operate_with(&x))
+            .unwrap();
+        for window_size in 1..=8 {
+            assert_eq!(
+                pippenger(&cs, &hidings, window_size, IsGroup::operate_with),
+                expected
+            );
+        }
+        assert_eq!(msm(&cs, &hidings), expected);
+        assert_eq!(msm_with_xyzz_buckets(&cs, &hidings), expected);
+    }
+
+    #[test]
+    fn msm_with_xyzz_buckets_matches_msm() {
+        let g = BLS12381Curve::generator();
+        let cs: Vec<_> = (0..40_u64)
+            .map(|i| U256::from_u64(i.wrapping_mul(0x9e3779b97f4a7c15) + 1))
+            .collect();
+        let mut points: Vec<_> = (0..40_u128).map(|i| g.operate_with_self(i)).collect();
+        points[7] = points[7].to_affine();
+        assert_eq!(msm_with_xyzz_buckets(&cs, &points), msm(&cs, &points));
+    }
+
+    #[test]
+    fn msm_with_xyzz_buckets_with_empty_input() {
+        let c: [U64; 0] = [];
+        let points: [ShortWeierstrassProjectivePoint<TestCurve1>; 0] = [];
+        assert!(msm_with_xyzz_buckets(&c, &points).is_neutral_element());
+    }
+
+    #[test]
+    fn msm_with_representatives_of_prime_field_elements() {
+        let g = BLS12381Curve::generator();
+        let cs: Vec<U384> = [7_u64, 11, 13]
+            .iter()
+            .map(|c| FieldElement::<BLS12381PrimeField>::from(*c).representative())
+            .collect();
+        let hidings = [g.clone(), g.operate_with_self(2), g.operate_with_self(3)];
+        assert_eq!(msm(&cs, &hidings), g.operate_with_self(7 + 22 + 39));
+    }
 }
@@ -1,4 +1,5 @@
 use super::field::element::FieldElement;
+use crate::field::errors::FieldError;
 use crate::field::traits::IsField;
 use std::ops;
 
@@ -35,21 +36,23 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
         Self::new(&[])
     }
 
-    pub fn interpolate(xs: &[FieldElement<F>], ys: &[FieldElement<F>]) -> Self {
+    /// Returns the polynomial of lowest degree that takes the value `ys[i]` at `xs[i]`.
+    /// Returns an error if two of the `xs` are equal.
+    pub fn interpolate(xs: &[FieldElement<F>], ys: &[FieldElement<F>]) -> Result<Self, FieldError> {
         let mut result = Polynomial::zero();
 
         for (i, y) in ys.iter().enumerate() {
-            let mut y_term = Polynomial::new(&[y.clone()]);
+            let mut y_term = Polynomial::new(std::slice::from_ref(y));
             for (j, x) in xs.iter().enumerate() {
                 if i != j {
-                    let denominator = Polynomial::new(&[FieldElement::one() / (&xs[i] - x)]);
+                    let denominator = Polynomial::new(&[(&xs[i] - x).try_inv()?]);
                     let numerator = Polynomial::new(&[-x, FieldElement::one()]);
                     y_term = y_term.mul_with_ref(&(numerator * denominator));
                 }
             }
             result = result + y_term;
         }
-        result
+        Ok(result)
     }
 
     pub fn evaluate(&self, x: &FieldElement<F>) -> FieldElement<F> {
@@ -213,6 +216,9 @@ impl<F: IsField> ops::Div<Polynomial<FieldElement<F>>> for Polynomial<FieldEleme
     }
 }
 
+/// Always multiplies with the schoolbook method, because it is implemented for every field.
+/// Over two-adic fields, callers opt in to FFT multiplication of large polynomials with
+/// `Polynomial::fast_mul`.
 impl<F: IsField> ops::Mul<Polynomial<FieldElement<F>>> for Polynomial<FieldElement<F>> {
     type Output = Polynomial<FieldElement<F>>;
     fn mul(self, dividend: Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
@@ -446,13 +452,14 @@ mod tests {
 
     #[test]
     fn interpolate_x_2_y_3() {
-        let p = Polynomial::interpolate(&[FE::new(2)], &[FE::new(3)]);
+        let p = Polynomial::interpolate(&[FE::new(2)], &[FE::new(3)]).unwrap();
         assert_eq!(FE::new(3), p.evaluate(&FE::new(2)));
     }
 
     #[test]
     fn interpolate_x_0_2_y_3_4() {
-        let p = Polynomial::interpolate(&[FE::new(0), FE::new(2)], &[FE::new(3), FE::new(4)]);
+        let p =
+            Polynomial::interpolate(&[FE::new(0), FE::new(2)], &[FE::new(3), FE::new(4)]).unwrap();
         assert_eq!(FE::new(3), p.evaluate(&FE::new(0)));
         assert_eq!(FE::new(4), p.evaluate(&FE::new(2)));
     }
@@ -462,7 +469,8 @@ mod tests {
         let p = Polynomial::interpolate(
             &[FE::new(2), FE::new(5), FE::new(7)],
             &[FE::new(10), FE::new(19), FE::new(43)],
-        );
+        )
+        .unwrap();
 
         assert_eq!(FE::new(10), p.evaluate(&FE::new(2)));
         assert_eq!(FE::new(19), p.evaluate(&FE::new(5)));
@@ -471,7 +479,8 @@ mod tests {
 
     #[test]
     fn interpolate_x_0_0_y_1_1() {
-        let p = Polynomial::interpolate(&[FE::new(0), FE::new(1)], &[FE::new(0), FE::new(1)]);
+        let p =
+            Polynomial::interpolate(&[FE::new(0), FE::new(1)], &[FE::new(0), FE::new(1)]).unwrap();
 
         assert_eq!(FE::new(0), p.evaluate(&FE::new(0)));
         assert_eq!(FE::new(1), p.evaluate(&FE::new(1)));
@@ -479,7 +488,13 @@ mod tests {
 
     #[test]
     fn interpolate_x_0_y_0() {
-        let p = Polynomial::interpolate(&[FE::new(0)], &[FE::new(0)]);
+        let p = Polynomial::interpolate(&[FE::new(0)], &[FE::new(0)]).unwrap();
         assert_eq!(FE::new(0), p.evaluate(&FE::new(0)));
     }
+
+    #[test]
+    fn interpolate_with_repeated_xs_is_an_error() {
+        let result = Polynomial::interpolate(&[FE::new(2), FE::new(2)], &[FE::new(3), FE::new(4)]);
+        assert_eq!(result, Err(FieldError::DivisionByZero));
+    }
 }
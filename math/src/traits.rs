@@ -1,4 +1,5 @@
 use crate::errors::ByteConversionError;
+use rand::Rng;
 
 /// A trait for converting an element to and from its byte representation and
 /// for getting an element from its byte representation in big-endian or
@@ -20,3 +21,9 @@ pub trait ByteConversion {
     where
         Self: std::marker::Sized;
 }
+
+/// A trait for sampling elements uniformly at random from a source of randomness.
+pub trait RandomSample {
+    /// Returns an element sampled uniformly at random with `rng`.
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
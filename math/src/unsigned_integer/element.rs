@@ -1,9 +1,12 @@
 use std::convert::From;
+use std::fmt;
 use std::ops::{Add, BitAnd, Mul, Shl, Shr, Sub};
+use std::str::FromStr;
 
-use crate::errors::ByteConversionError;
-use crate::traits::ByteConversion;
+use crate::errors::{ByteConversionError, ParseUnsignedIntegerError};
+use crate::traits::{ByteConversion, RandomSample};
 use crate::unsigned_integer::traits::IsUnsignedInteger;
+use rand::Rng;
 
 use std::fmt::Debug;
 
@@ -49,6 +52,53 @@ impl<const NUM_LIMBS: usize> From<&str> for UnsignedInteger<NUM_LIMBS> {
     }
 }
 
+/// Parses a decimal string, or a hexadecimal one if it starts with `0x`.
+impl<const NUM_LIMBS: usize> FromStr for UnsignedInteger<NUM_LIMBS> {
+    type Err = ParseUnsignedIntegerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x") {
+            Some(hex_str) => Self::from_hex_str(hex_str),
+            None => Self::from_dec_str(s),
+        }
+    }
+}
+
+/// Formats the integer in decimal.
+impl<const NUM_LIMBS: usize> fmt::Display for UnsignedInteger<NUM_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Splits the integer in chunks of 19 decimal digits, the most that fit in a `u64`.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        let mut quotient = *self;
+        loop {
+            let (q, r) = quotient.div_rem_u64(CHUNK);
+            chunks.push(r);
+            quotient = q;
+            if quotient == Self::from_u64(0) {
+                break;
+            }
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl<const NUM_LIMBS: usize> fmt::LowerHex for UnsignedInteger<NUM_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.hex_digits())
+    }
+}
+
+impl<const NUM_LIMBS: usize> fmt::UpperHex for UnsignedInteger<NUM_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.hex_digits().to_uppercase())
+    }
+}
+
 // impl Add
 
 impl<const NUM_LIMBS: usize> Add<&UnsignedInteger<NUM_LIMBS>> for &UnsignedInteger<NUM_LIMBS> {
@@ -266,7 +316,7 @@ impl<const NUM_LIMBS: usize> Shr<usize> for UnsignedInteger<NUM_LIMBS> {
     }
 }
 
-/// Impl BitAnd
+// impl BitAnd
 
 impl<const NUM_LIMBS: usize> BitAnd for UnsignedInteger<NUM_LIMBS> {
     type Output = Self;
@@ -304,14 +354,20 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         let mut i: usize = value.len();
         while i > 0 {
             i -= 1;
-            limb |= match value[i] {
-                c @ b'0'..=b'9' => (c as u64 - '0' as u64) << shift,
-                c @ b'a'..=b'f' => (c as u64 - 'a' as u64 + 10) << shift,
-                c @ b'A'..=b'F' => (c as u64 - 'A' as u64 + 10) << shift,
+            let digit = match value[i] {
+                c @ b'0'..=b'9' => c as u64 - '0' as u64,
+                c @ b'a'..=b'f' => c as u64 - 'a' as u64 + 10,
+                c @ b'A'..=b'F' => c as u64 - 'A' as u64 + 10,
                 _ => {
                     panic!("Malformed hex expression.")
                 }
             };
+            if shift < 64 {
+                limb |= digit << shift;
+            } else {
+                // Only leading zeros are allowed beyond the most significant limb.
+                assert!(digit == 0, "Hex expression does not fit in the limbs.");
+            }
             shift += 4;
             if shift == 64 && limb_index > 0 {
                 result[limb_index] = limb;
@@ -325,6 +381,48 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         UnsignedInteger { limbs: result }
     }
 
+    /// Parses a hexadecimal string, without prefix. Leading zeros are allowed.
+    pub fn from_hex_str(hex_str: &str) -> Result<Self, ParseUnsignedIntegerError> {
+        if hex_str.is_empty() {
+            return Err(ParseUnsignedIntegerError::Empty);
+        }
+        let mut limbs = [0u64; NUM_LIMBS];
+        for (i, c) in hex_str.bytes().rev().enumerate() {
+            let digit = (c as char)
+                .to_digit(16)
+                .ok_or(ParseUnsignedIntegerError::InvalidDigit)? as u64;
+            if i >= 16 * NUM_LIMBS {
+                if digit != 0 {
+                    return Err(ParseUnsignedIntegerError::Overflow);
+                }
+            } else {
+                limbs[NUM_LIMBS - 1 - i / 16] |= digit << (4 * (i % 16));
+            }
+        }
+        Ok(Self { limbs })
+    }
+
+    /// Parses a decimal string. Leading zeros are allowed.
+    pub fn from_dec_str(dec_str: &str) -> Result<Self, ParseUnsignedIntegerError> {
+        if dec_str.is_empty() {
+            return Err(ParseUnsignedIntegerError::Empty);
+        }
+        let ten = Self::from_u64(10);
+        let mut result = Self::from_u64(0);
+        for c in dec_str.chars() {
+            let digit = c
+                .to_digit(10)
+                .ok_or(ParseUnsignedIntegerError::InvalidDigit)?;
+            let (hi, lo) = Self::mul_wide(&result, &ten);
+            let (sum, overflow) = Self::add(&lo, &Self::from_u64(digit as u64));
+            if hi != Self::from_u64(0) || overflow {
+                return Err(ParseUnsignedIntegerError::Overflow);
+            }
+            result = sum;
+        }
+        Ok(result)
+    }
+
     pub const fn const_le(a: &UnsignedInteger<NUM_LIMBS>, b: &UnsignedInteger<NUM_LIMBS>) -> bool {
         let mut i = 0;
         while i < NUM_LIMBS {
@@ -377,10 +475,10 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         (Self { limbs }, carry < 0)
     }
 
-    /// Multi-precision multiplication.
+    /// Multi-precision multiplication without overflow. Returns the pair `(hi, lo)`
+    /// of the most and least significant halves of the product `a * b`.
     /// Adapted from Algorithm 14.12 of "Handbook of Applied Cryptography" (https://cacr.uwaterloo.ca/hac/)
-    #[allow(unused)]
-    fn mul(
+    pub fn mul_wide(
         a: &UnsignedInteger<NUM_LIMBS>,
         b: &UnsignedInteger<NUM_LIMBS>,
     ) -> (UnsignedInteger<NUM_LIMBS>, UnsignedInteger<NUM_LIMBS>) {
@@ -395,7 +493,7 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
             for j in (0..NUM_LIMBS).rev() {
                 let mut k = i + j;
                 if k >= NUM_LIMBS - 1 {
-                    k -= (NUM_LIMBS - 1);
+                    k -= NUM_LIMBS - 1;
                     let uv = (lo[k] as u128) + (a.limbs[j] as u128) * (b.limbs[i] as u128) + carry;
                     carry = uv >> 64;
                     // Casting u128 to u64 takes modulo 2^{64}
@@ -414,6 +512,185 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         // 3.
         (Self { limbs: hi }, Self { limbs: lo })
     }
+
+    /// Returns the quotient and the remainder of the division of `self` by `divisor`.
+    /// Computed with binary long division.
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(*divisor != Self::from_u64(0), "Division by zero.");
+        let mut quotient = Self::from_u64(0);
+        let mut remainder = Self::from_u64(0);
+        for i in (0..self.bits()).rev() {
+            let overflow = remainder.shl_one_with_carry(self.bit(i));
+            if overflow || remainder >= *divisor {
+                (remainder, _) = Self::sub(&remainder, divisor);
+                quotient.limbs[NUM_LIMBS - 1 - i / 64] |= 1 << (i % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns `self mod modulus`.
+    /// Panics if `modulus` is zero.
+    pub fn rem(&self, modulus: &Self) -> Self {
+        self.div_rem(modulus).1
+    }
+
+    /// Returns `self^exponent mod modulus`, computed with square and multiply.
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self {
+        let base = self.rem(modulus);
+        let mut result = Self::from_u64(1).rem(modulus);
+        for i in (0..exponent.bits()).rev() {
+            result = Self::mul_mod(&result, &result, modulus);
+            if exponent.bit(i) {
+                result = Self::mul_mod(&result, &base, modulus);
+            }
+        }
+        result
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, or `None` if they are not coprime.
+    /// Computed with the extended Euclidean algorithm.
+    /// Panics if `modulus` is zero.
+    pub fn inv_mod(&self, modulus: &Self) -> Option<Self> {
+        let zero = Self::from_u64(0);
+        let one = Self::from_u64(1);
+        // Invariant: `t0 * self = r0` and `t1 * self = r1` modulo `modulus`.
+        let (mut r0, mut r1) = (*modulus, self.rem(modulus));
+        let (mut t0, mut t1) = (zero, one.rem(modulus));
+        while r1 != zero {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            let q_t1 = Self::mul_mod(&q, &t1, modulus);
+            let t = if t0 >= q_t1 {
+                Self::sub(&t0, &q_t1).0
+            } else {
+                Self::sub(modulus, &Self::sub(&q_t1, &t0).0).0
+            };
+            (t0, t1) = (t1, t);
+        }
+        (r0 == one).then_some(t0)
+    }
+
+    /// Returns `(hi * 2^(64 * NUM_LIMBS) + lo) mod modulus`, the reduction of the double-width
+    /// integer whose most and least significant halves are `hi` and `lo`, as returned by
+    /// `mul_wide`. Computed with binary long division, subtracting the modulus with a mask
+    /// instead of a branch, so that the running time does not depend on `hi` and `lo`
+    /// and secret values, such as hash outputs turned into keys, can be reduced.
+    /// Panics if `modulus` is zero.
+    pub fn reduce_wide(hi: &Self, lo: &Self, modulus: &Self) -> Self {
+        assert!(*modulus != Self::from_u64(0), "Division by zero.");
+        let mut remainder = Self::from_u64(0);
+        for half in [hi, lo] {
+            for i in (0..64 * NUM_LIMBS).rev() {
+                let overflow = remainder.shl_one_with_carry(half.bit(i));
+                let (difference, borrow) = Self::sub(&remainder, modulus);
+                // All ones if `remainder >= modulus`, counting the bit shifted out.
+                let mask = 0u64.wrapping_sub((overflow | !borrow) as u64);
+                for (limb, difference_limb) in remainder.limbs.iter_mut().zip(difference.limbs) {
+                    *limb ^= (*limb ^ difference_limb) & mask;
+                }
+            }
+        }
+        remainder
+    }
+
+    /// Returns `a * b mod modulus`.
+    fn mul_mod(a: &Self, b: &Self, modulus: &Self) -> Self {
+        let (hi, lo) = Self::mul_wide(a, b);
+        Self::reduce_wide(&hi, &lo, modulus)
+    }
+
+    /// Returns the quotient and the remainder of the division of `self` by the nonzero `divisor`.
+    fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut quotient = [0u64; NUM_LIMBS];
+        let mut remainder = 0u128;
+        for (q, limb) in quotient.iter_mut().zip(self.limbs) {
+            let dividend = (remainder << 64) | limb as u128;
+            *q = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (Self { limbs: quotient }, remainder as u64)
+    }
+
+    /// Returns the hexadecimal digits of `self` in lowercase, without leading zeros.
+    fn hex_digits(&self) -> String {
+        match self.limbs.iter().position(|limb| *limb != 0) {
+            Some(i) => {
+                let mut digits = format!("{:x}", self.limbs[i]);
+                for limb in &self.limbs[i + 1..] {
+                    digits.push_str(&format!("{limb:016x}"));
+                }
+                digits
+            }
+            None => "0".to_string(),
+        }
+    }
+
+    /// Returns the number of significant bits of `self`.
+    fn bits(&self) -> usize {
+        self.limbs
+            .iter()
+            .position(|limb| *limb != 0)
+            .map_or(0, |i| {
+                64 * (NUM_LIMBS - i) - self.limbs[i].leading_zeros() as usize
+            })
+    }
+
+    /// Returns the `i`-th bit of `self`, where bit 0 is the least significant one.
+    fn bit(&self, i: usize) -> bool {
+        (self.limbs[NUM_LIMBS - 1 - i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// Shifts `self` one bit to the left, setting `bit` as the least significant one.
+    /// Returns the bit shifted out.
+    fn shl_one_with_carry(&mut self, bit: bool) -> bool {
+        let mut carry = bit as u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let next_carry = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+        carry == 1
+    }
+}
+
+impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
+    /// Returns an integer sampled uniformly at random in the range `[0, bound)`.
+    /// Integers with the bit length of `bound` are sampled until one is smaller than it,
+    /// which takes less than two attempts on average.
+    /// Panics if `bound` is zero.
+    pub fn random_below<R: Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self {
+        assert!(
+            *bound != Self::from_u64(0),
+            "The bound must be greater than zero."
+        );
+        let bits = bound.bits();
+        loop {
+            let mut sample = Self::random(rng);
+            for (i, limb) in sample.limbs.iter_mut().rev().enumerate() {
+                let limb_bits = bits.saturating_sub(64 * i).min(64);
+                *limb = if limb_bits == 0 {
+                    0
+                } else {
+                    *limb >> (64 - limb_bits)
+                };
+            }
+            if sample < *bound {
+                return sample;
+            }
+        }
+    }
+}
+
+/// Samples an integer uniformly at random in the range `[0, 2^(64 * NUM_LIMBS))`.
+impl<const NUM_LIMBS: usize> RandomSample for UnsignedInteger<NUM_LIMBS> {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut limbs = [0u64; NUM_LIMBS];
+        rng.fill(&mut limbs[..]);
+        Self { limbs }
+    }
 }
 
 impl<const NUM_LIMBS: usize> IsUnsignedInteger for UnsignedInteger<NUM_LIMBS> {}
@@ -574,6 +851,18 @@ mod tests_u384 {
         );
     }
 
+    #[test]
+    #[should_panic]
+    fn construct_new_integer_from_hex_that_does_not_fit_panics() {
+        UnsignedInteger::<1>::from("10000000000000000");
+    }
+
+    #[test]
+    fn construct_new_integer_from_hex_with_leading_zeros_beyond_the_limbs() {
+        let a = UnsignedInteger::<1>::from("0000ffffffffffffffff");
+        assert_eq!(a.limbs, [u64::MAX]);
+    }
+
     #[test]
     fn equality_works_1() {
         let a = U384::from("1");
@@ -971,7 +1260,7 @@ mod tests_u384 {
         let lo_expected = U384::from(
             "375342999dab7f52f4010c4abc2e18b55218015931a55d6053ac39e86e2a47d6b1cb95f41680",
         );
-        let (hi, lo) = U384::mul(&a, &b);
+        let (hi, lo) = U384::mul_wide(&a, &b);
         assert_eq!(hi, hi_expected);
         assert_eq!(lo, lo_expected);
     }
@@ -984,7 +1273,7 @@ mod tests_u384 {
             "1742daad9c7861dd3499e7ece65467e337937b27e20d641b225bfe00323d33ed62715654eadc092b057a5f19f2ad6c",
         );
         let lo_expected = U384::from("9969c0417b9304d9c16b046c860447d3533999e16710d2e90a44959a168816c015ffb44b987e8cbb82bd46b08d9e2106");
-        let (hi, lo) = U384::mul(&a, &b);
+        let (hi, lo) = U384::mul_wide(&a, &b);
         assert_eq!(hi, hi_expected);
         assert_eq!(lo, lo_expected);
     }
@@ -995,7 +1284,7 @@ mod tests_u384 {
         let b = U384::from("2");
         let hi_expected = U384::from("1");
         let lo_expected = U384::from("0");
-        let (hi, lo) = U384::mul(&a, &b);
+        let (hi, lo) = U384::mul_wide(&a, &b);
         assert_eq!(hi, hi_expected);
         assert_eq!(lo, lo_expected);
     }
@@ -1155,6 +1444,9 @@ mod tests_u384 {
 #[cfg(test)]
 mod tests_u256 {
     use super::UnsignedInteger;
+    use crate::errors::ParseUnsignedIntegerError;
+    use crate::traits::RandomSample;
+    use rand::{rngs::StdRng, SeedableRng};
     const NUM_LIMBS: usize = 4;
     type U256 = UnsignedInteger<NUM_LIMBS>;
 
@@ -1614,7 +1906,7 @@ mod tests_u256 {
         let c_expected =
             U256::from("114ec14db0c80d30b7dcb9c45948ef04cc149e612cb544f447b146553aff2ac3");
 
-        let (overflow, c) = U256::mul(&a, &b);
+        let (overflow, c) = U256::mul_wide(&a, &b);
 
         assert_eq!(c, c_expected);
         assert_eq!(overflow, U256::from("0"));
@@ -1628,7 +1920,7 @@ mod tests_u256 {
             U256::from("46A946D6A984FE6507DE6B8D1354256D7A7BAE4283404733BDC876A264BCE5EE");
         let lo_expected =
             U256::from("43F24263F10930EBE3EA0307466C19B13B9C7DBA6B3F7604B7F32FB0E3084EA0");
-        let (hi, lo) = U256::mul(&a, &b);
+        let (hi, lo) = U256::mul_wide(&a, &b);
         assert_eq!(hi, hi_expected);
         assert_eq!(lo, lo_expected);
     }
@@ -1737,4 +2029,278 @@ mod tests_u256 {
         let b = U256::from("5322c128ec84081b6c376c108ebd7fd3");
         assert_eq!(&a >> (64 * 2), b);
     }
+
+    #[test]
+    fn div_rem_works() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        let b = U256::from("7f3ad1611ab58212f92a2484e9560935");
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, U256::from("11e13d9f678f66cf9d60a978b344c296f"));
+        assert_eq!(r, U256::from("34dfc3154810a4f8deca77ef27bdd332"));
+        assert_eq!(a.rem(&b), r);
+    }
+
+    #[test]
+    fn div_rem_by_a_larger_integer_is_zero_and_the_dividend() {
+        let a = U256::from("7f3ad1611ab58212f92a2484e9560935");
+        let b = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        assert_eq!(a.div_rem(&b), (U256::from_u64(0), a));
+    }
+
+    #[test]
+    fn div_rem_with_the_maximum_value_works() {
+        let max = U256::from("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        assert_eq!(max.div_rem(&max), (U256::from_u64(1), U256::from_u64(0)));
+        assert_eq!(
+            max.div_rem(&U256::from_u64(2)),
+            (max >> 1, U256::from_u64(1))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_rem_by_zero_panics() {
+        U256::from_u64(5).div_rem(&U256::from_u64(0));
+    }
+
+    #[test]
+    fn pow_mod_works() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        let e = U256::from("123456789abcdef0fedcba987654321");
+        let p = U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(
+            a.pow_mod(&e, &p),
+            U256::from("6eff76610f73449fae50452f48fec4812af1fb207aa4faa9b61271ded27963f")
+        );
+    }
+
+    #[test]
+    fn pow_mod_reduces_products_larger_than_the_limbs() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        let e = U256::from("7f3ad1611ab58212f92a2484e9560935");
+        let m = U256::from("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        assert_eq!(
+            a.pow_mod(&e, &m),
+            U256::from("a45995dda2e50ba952df7bd8ddd3f48c92fd15f2b488c193c50e9af646b351b8")
+        );
+        assert_eq!(
+            a.pow_mod(&U256::from_u64(0), &U256::from_u64(1)),
+            U256::from_u64(0)
+        );
+    }
+
+    #[test]
+    fn reduce_wide_matches_the_remainder_of_the_double_width_integer() {
+        let p = U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        let max = U256::from("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        for (hi, lo) in [
+            (a, max),
+            (max, a),
+            (max, max),
+            (U256::from_u64(0), a),
+            (p, p),
+        ] {
+            let mut wide = UnsignedInteger::<8>::from_u64(0);
+            wide.limbs[..4].copy_from_slice(&hi.limbs);
+            wide.limbs[4..].copy_from_slice(&lo.limbs);
+            let mut wide_p = UnsignedInteger::<8>::from_u64(0);
+            wide_p.limbs[4..].copy_from_slice(&p.limbs);
+            let expected = wide.rem(&wide_p);
+            assert_eq!(expected.limbs[..4], [0; 4]);
+            assert_eq!(U256::reduce_wide(&hi, &lo, &p).limbs, expected.limbs[4..]);
+        }
+        assert_eq!(
+            U256::reduce_wide(&U256::from_u64(1), &U256::from_u64(5), &max),
+            U256::from_u64(6)
+        );
+    }
+
+    #[test]
+    fn inv_mod_works() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        let p = U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(
+            a.inv_mod(&p),
+            Some(U256::from(
+                "47eb2b8008840816fec6f78e4c0d50b0c1e0dcbc3ae30d7b13face26f01502e4"
+            ))
+        );
+    }
+
+    #[test]
+    fn inv_mod_of_non_coprime_integers_is_none() {
+        assert_eq!(U256::from_u64(6).inv_mod(&U256::from_u64(15)), None);
+        assert_eq!(U256::from_u64(15).inv_mod(&U256::from_u64(15)), None);
+        assert_eq!(
+            U256::from_u64(7).inv_mod(&U256::from_u64(15)),
+            Some(U256::from_u64(13))
+        );
+    }
+
+    #[test]
+    fn from_hex_str_works() {
+        let a =
+            U256::from_hex_str("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        assert_eq!(
+            a,
+            Ok(U256::from(
+                "8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d"
+            ))
+        );
+        assert_eq!(
+            U256::from_hex_str(
+                "00000000000000000000000000000000000000000000000000000000000000000001F"
+            ),
+            Ok(U256::from_u64(31))
+        );
+    }
+
+    #[test]
+    fn from_hex_str_rejects_invalid_input() {
+        assert_eq!(
+            U256::from_hex_str(""),
+            Err(ParseUnsignedIntegerError::Empty)
+        );
+        assert_eq!(
+            U256::from_hex_str("12g4"),
+            Err(ParseUnsignedIntegerError::InvalidDigit)
+        );
+        assert_eq!(
+            U256::from_hex_str("0x12"),
+            Err(ParseUnsignedIntegerError::InvalidDigit)
+        );
+        assert_eq!(
+            U256::from_hex_str("18e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d"),
+            Err(ParseUnsignedIntegerError::Overflow)
+        );
+    }
+
+    #[test]
+    fn from_dec_str_works() {
+        assert_eq!(
+            U256::from_dec_str(
+                "64308951362370953328772045987127452820692173953073782709969320048933677911597"
+            ),
+            Ok(U256::from(
+                "8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d"
+            ))
+        );
+        assert_eq!(U256::from_dec_str("000"), Ok(U256::from_u64(0)));
+    }
+
+    #[test]
+    fn from_dec_str_rejects_invalid_input() {
+        assert_eq!(
+            U256::from_dec_str(""),
+            Err(ParseUnsignedIntegerError::Empty)
+        );
+        assert_eq!(
+            U256::from_dec_str("12a"),
+            Err(ParseUnsignedIntegerError::InvalidDigit)
+        );
+        assert_eq!(
+            U256::from_dec_str("-1"),
+            Err(ParseUnsignedIntegerError::InvalidDigit)
+        );
+        assert_eq!(
+            U256::from_dec_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            ),
+            Ok(U256::from(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+            ))
+        );
+        assert_eq!(
+            U256::from_dec_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            ),
+            Err(ParseUnsignedIntegerError::Overflow)
+        );
+    }
+
+    #[test]
+    fn from_str_parses_decimal_and_prefixed_hex() {
+        assert_eq!("255".parse::<U256>(), Ok(U256::from_u64(255)));
+        assert_eq!("0xff".parse::<U256>(), Ok(U256::from_u64(255)));
+        assert_eq!(
+            "ff".parse::<U256>(),
+            Err(ParseUnsignedIntegerError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn display_formats_in_decimal() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        assert_eq!(
+            a.to_string(),
+            "64308951362370953328772045987127452820692173953073782709969320048933677911597"
+        );
+        assert_eq!(U256::from_u64(0).to_string(), "0");
+        assert_eq!(
+            U256::from_u128(10_000_000_000_000_000_000).to_string(),
+            "10000000000000000000"
+        );
+        assert_eq!(format!("{:>5}", U256::from_u64(42)), "   42");
+    }
+
+    #[test]
+    fn hex_formatting_works() {
+        let a = U256::from("00e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        assert_eq!(
+            format!("{a:x}"),
+            "e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d"
+        );
+        assert_eq!(
+            format!("{a:#X}"),
+            "0xE2D939B602A50911232731D04FE6F40C05F97DA0602307099FB991F9B414E2D"
+        );
+        assert_eq!(format!("{:x}", U256::from_u64(0)), "0");
+        assert_eq!(
+            format!("{:x}", U256::from_u128(1 << 64)),
+            "10000000000000000"
+        );
+    }
+
+    #[test]
+    fn formatting_and_parsing_round_trip() {
+        let a = U256::from("8e2d939b602a50911232731d04fe6f40c05f97da0602307099fb991f9b414e2d");
+        assert_eq!(a.to_string().parse::<U256>(), Ok(a));
+        assert_eq!(format!("{a:#x}").parse::<U256>(), Ok(a));
+    }
+
+    #[test]
+    fn random_below_is_smaller_than_the_bound() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let bound = U256::from("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        for _ in 0..100 {
+            assert!(U256::random_below(&mut rng, &bound) < bound);
+        }
+    }
+
+    #[test]
+    fn random_below_a_small_bound_takes_every_value() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            let sample = U256::random_below(&mut rng, &U256::from_u64(5));
+            seen[sample.limbs[3] as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn random_uses_every_limb() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let sample = U256::random(&mut rng);
+        assert!(sample.limbs.iter().all(|limb| *limb != 0));
+        assert_ne!(sample, U256::random(&mut rng));
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_below_zero_panics() {
+        let mut rng = StdRng::seed_from_u64(42);
+        U256::random_below(&mut rng, &U256::from_u64(0));
+    }
 }
@@ -60,20 +60,198 @@ impl MontgomeryAlgorithms {
         }
         let mut result = UnsignedInteger { limbs: t };
 
-        let overflow = t_extra[0] > 0;
-        // TODO: assuming the integer represented by
-        // [t_extra[1], t[0], ..., t[NUM_LIMBS - 1]] is at most
-        // 2q in any case.
-        if overflow || UnsignedInteger::const_le(q, &result) {
-            (result, _) = UnsignedInteger::sub(&result, q);
+        // The result is the integer represented by [t_extra[1], t[0], ..., t[NUM_LIMBS - 1]],
+        // which is smaller than 2q. It is reduced, without branches, if it overflows or if
+        // subtracting `q` does not borrow.
+        let (reduced, borrow) = UnsignedInteger::sub(&result, q);
+        let mask = ((t_extra[1] > 0) as u64 | !borrow as u64).wrapping_neg();
+        let mut i = 0;
+        while i < NUM_LIMBS {
+            result.limbs[i] ^= (result.limbs[i] ^ reduced.limbs[i]) & mask;
+            i += 1;
         }
         result
     }
+
+    /// Computes `-q^{-1} mod 2^64`, the constant used by `cios` for the modulus `q`.
+    /// Panics, at compile time when used in a constant, if `q` is even.
+    pub const fn compute_mp<const NUM_LIMBS: usize>(q: &UnsignedInteger<NUM_LIMBS>) -> u64 {
+        let q0 = q.limbs[NUM_LIMBS - 1];
+        assert!(q0 & 1 == 1, "The modulus must be odd");
+        // Newton's iteration doubles the number of correct bits of the inverse at each step,
+        // starting from the 3 bits given by `q0`, since `q0 * q0 = 1 mod 8`.
+        let mut inverse = q0;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2_u64.wrapping_sub(q0.wrapping_mul(inverse)));
+            i += 1;
+        }
+        inverse.wrapping_neg()
+    }
+
+    /// Computes `R^2 mod q`, where `R = 2^(64 * NUM_LIMBS)`.
+    /// Panics, at compile time when used in a constant, if `q` is even or smaller than 3.
+    pub const fn compute_r2<const NUM_LIMBS: usize>(
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        assert!(q.limbs[NUM_LIMBS - 1] & 1 == 1, "The modulus must be odd");
+        assert!(
+            UnsignedInteger::const_le(&UnsignedInteger::from_u64(1), q),
+            "The modulus must be greater than one"
+        );
+        // Starts from 1 and doubles modulo `q` as many times as the bits of `R^2`.
+        let mut result = UnsignedInteger::from_u64(1);
+        let mut i = 0;
+        while i < 128 * NUM_LIMBS {
+            let overflow = result.limbs[0] >> 63 == 1;
+            let mut j = 0;
+            while j < NUM_LIMBS - 1 {
+                result.limbs[j] = (result.limbs[j] << 1) | (result.limbs[j + 1] >> 63);
+                j += 1;
+            }
+            result.limbs[NUM_LIMBS - 1] <<= 1;
+            if overflow || !UnsignedInteger::const_le(&result, q) {
+                (result, _) = UnsignedInteger::sub(&result, q);
+            }
+            i += 1;
+        }
+        result
+    }
+
+    /// Computes the Montgomery form of the inverse of the element with Montgomery form `a`,
+    /// that is, `R^2 / a mod q`, where `R = 2^(64 * NUM_LIMBS)`, `r2 = R^2 mod q` and
+    /// `mp = -q^{-1} mod 2^64`.
+    /// The binary extended Euclidean algorithm computes `a^{-1} mod q`, which is
+    /// then multiplied by `R^2` with two Montgomery multiplications by `r2`, each of which
+    /// multiplies by `R^2 * R^{-1} = R`.
+    /// Runs in time that depends on `a`. Panics if `a` is zero.
+    /// The modulus `q` must be odd and `a` must be smaller than `q` and coprime with it.
+    pub fn inv_binary_gcd<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+        r2: &UnsignedInteger<NUM_LIMBS>,
+        mp: &u64,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let one = UnsignedInteger::from_u64(1);
+        assert!(
+            *a != UnsignedInteger::from_u64(0),
+            "Division by zero error."
+        );
+        // Invariants: `x1 * a = u` and `x2 * a = v` modulo `q`.
+        let (mut u, mut v) = (*a, *q);
+        let (mut x1, mut x2) = (one, UnsignedInteger::from_u64(0));
+        while u != one && v != one {
+            while Self::is_even(&u) {
+                u = u >> 1;
+                x1 = Self::half_mod(&x1, q);
+            }
+            while Self::is_even(&v) {
+                v = v >> 1;
+                x2 = Self::half_mod(&x2, q);
+            }
+            if u >= v {
+                u = u - v;
+                x1 = Self::sub_mod(&x1, &x2, q);
+            } else {
+                v = v - u;
+                x2 = Self::sub_mod(&x2, &x1, q);
+            }
+        }
+        let inverse = if u == one { x1 } else { x2 };
+        Self::cios(&Self::cios(&inverse, r2, q, mp), r2, q, mp)
+    }
+
+    /// Constant-time version of `inv_binary_gcd`, for secret values of `a`.
+    /// Runs a fixed number of iterations of the binary extended Euclidean algorithm
+    /// without branches nor memory accesses depending on `a`.
+    /// Returns zero if `a` is zero.
+    /// The modulus `q` must be odd and `a` must be smaller than `q` and coprime with it.
+    pub fn inv_binary_gcd_constant_time<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+        r2: &UnsignedInteger<NUM_LIMBS>,
+        mp: &u64,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        // Invariants: `x1 * a = u` and `x2 * a = v` modulo `q`, and `v` is odd.
+        // Each iteration decreases the sum of the bit lengths of `u` and `v` unless `u` is zero,
+        // so after `128 * NUM_LIMBS` iterations `u` is zero and `v` is `gcd(a, q) = 1`.
+        let (mut u, mut v) = (*a, *q);
+        let (mut x1, mut x2) = (UnsignedInteger::from_u64(1), UnsignedInteger::from_u64(0));
+        for _ in 0..128 * NUM_LIMBS {
+            let u_is_odd = (u.limbs[NUM_LIMBS - 1] & 1).wrapping_neg();
+            // If `u` is odd and smaller than `v`, swap them so that `u - v` does not underflow.
+            let (_, u_is_smaller) = UnsignedInteger::sub(&u, &v);
+            let swap = u_is_odd & (u_is_smaller as u64).wrapping_neg();
+            Self::conditional_swap(&mut u, &mut v, swap);
+            Self::conditional_swap(&mut x1, &mut x2, swap);
+            // If `u` is odd, subtract `v` from it, which makes it even.
+            (u, _) = UnsignedInteger::sub(&u, &Self::mask(&v, u_is_odd));
+            x1 = Self::sub_mod(&x1, &Self::mask(&x2, u_is_odd), q);
+            u = u >> 1;
+            x1 = Self::half_mod(&x1, q);
+        }
+        Self::cios(&Self::cios(&x2, r2, q, mp), r2, q, mp)
+    }
+
+    fn is_even<const NUM_LIMBS: usize>(a: &UnsignedInteger<NUM_LIMBS>) -> bool {
+        a.limbs[NUM_LIMBS - 1] & 1 == 0
+    }
+
+    /// Returns `a` if `mask` is all ones, and zero if it is zero.
+    fn mask<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        mask: u64,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        UnsignedInteger {
+            limbs: a.limbs.map(|limb| limb & mask),
+        }
+    }
+
+    /// Swaps `a` and `b` if `mask` is all ones, and leaves them unchanged if it is zero.
+    fn conditional_swap<const NUM_LIMBS: usize>(
+        a: &mut UnsignedInteger<NUM_LIMBS>,
+        b: &mut UnsignedInteger<NUM_LIMBS>,
+        mask: u64,
+    ) {
+        for (a_limb, b_limb) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            let t = (*a_limb ^ *b_limb) & mask;
+            *a_limb ^= t;
+            *b_limb ^= t;
+        }
+    }
+
+    /// Returns `a - b mod q`, for `a` and `b` smaller than `q`, without branches.
+    fn sub_mod<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        b: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let (difference, borrow) = UnsignedInteger::sub(a, b);
+        let (result, _) =
+            UnsignedInteger::add(&difference, &Self::mask(q, (borrow as u64).wrapping_neg()));
+        result
+    }
+
+    /// Returns `a / 2 mod q`, for `a` smaller than the odd modulus `q`, without branches.
+    /// If `a` is odd, `q` is added to it before shifting.
+    fn half_mod<const NUM_LIMBS: usize>(
+        a: &UnsignedInteger<NUM_LIMBS>,
+        q: &UnsignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let is_odd = (a.limbs[NUM_LIMBS - 1] & 1).wrapping_neg();
+        let (sum, carry) = UnsignedInteger::add(a, &Self::mask(q, is_odd));
+        let mut result = sum >> 1;
+        result.limbs[0] |= (carry as u64) << 63;
+        result
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::unsigned_integer::{element::U384, montgomery::MontgomeryAlgorithms};
+    use crate::unsigned_integer::{
+        element::{UnsignedInteger, U384},
+        montgomery::MontgomeryAlgorithms,
+    };
 
     #[test]
     fn montgomery_multiplication_works_0() {
@@ -104,4 +282,56 @@ mod tests {
         let c = U384::from("8d65cdee621682815d59f465d2641eea8a1274dc");
         assert_eq!(MontgomeryAlgorithms::cios(&x, &r_mod_m, &m, &mp), c);
     }
+
+    #[test]
+    fn compute_mp_is_the_negated_inverse_of_the_modulus() {
+        let m = U384::from("cdb061954fdd36e5176f50dbdcfd349570a29ce1");
+        assert_eq!(MontgomeryAlgorithms::compute_mp(&m), 16085280245840369887);
+        let m = U384::from_u64(23);
+        assert_eq!(MontgomeryAlgorithms::compute_mp(&m), 3208129404123400281);
+        let m = UnsignedInteger::<1>::from_u64(0xffffffff00000001);
+        assert_eq!(MontgomeryAlgorithms::compute_mp(&m), 18446744069414584319);
+    }
+
+    #[test]
+    fn compute_r2_is_the_square_of_r_modulo_the_modulus() {
+        let m = U384::from("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        assert_eq!(
+            MontgomeryAlgorithms::compute_r2(&m),
+            U384::from("11988fe592cae3aa9a793e85b519952d67eb88a9939d83c08de5476c4c95b6d50a76e6a609d104f1f4df1f341c341746")
+        );
+        assert_eq!(
+            MontgomeryAlgorithms::compute_r2(&U384::from_u64(23)),
+            U384::from_u64(6)
+        );
+        let m = UnsignedInteger::<1>::from_u64(0xffffffff00000001);
+        assert_eq!(
+            MontgomeryAlgorithms::compute_r2(&m),
+            UnsignedInteger::from_u64(0xfffffffe00000001)
+        );
+    }
+
+    #[test]
+    fn inv_binary_gcd_inverts_in_montgomery_form() {
+        let m = U384::from_u64(23);
+        let r2 = MontgomeryAlgorithms::compute_r2(&m);
+        let mp = MontgomeryAlgorithms::compute_mp(&m);
+        let x = MontgomeryAlgorithms::cios(&U384::from_u64(11), &r2, &m, &mp);
+        // 11 * 21 = 1 mod 23.
+        let expected = MontgomeryAlgorithms::cios(&U384::from_u64(21), &r2, &m, &mp);
+        assert_eq!(
+            MontgomeryAlgorithms::inv_binary_gcd(&x, &m, &r2, &mp),
+            expected
+        );
+        assert_eq!(
+            MontgomeryAlgorithms::inv_binary_gcd_constant_time(&x, &m, &r2, &mp),
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn compute_mp_of_an_even_modulus_panics() {
+        MontgomeryAlgorithms::compute_mp(&U384::from_u64(24));
+    }
 }